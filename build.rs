@@ -0,0 +1,146 @@
+// Generates the embedded IEEE registry tables when the `oui-db` feature is enabled.
+//
+// Input files use the IEEE download format (`oui.csv`, `mam.csv`, `oui36.csv`,
+// `cid.csv`, `iab.csv`) and are read from `data/`, or from the directory named
+// by `MAC_ADDR_OUI_DIR` if set.
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+#[path = "src/oui/csv.rs"]
+mod csv;
+#[allow(dead_code)]
+#[path = "src/oui/kind.rs"]
+mod kind;
+
+use kind::Registry;
+
+const FILES: [&str; 5] = ["oui.csv", "mam.csv", "oui36.csv", "cid.csv", "iab.csv"];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/oui/csv.rs");
+    println!("cargo:rerun-if-changed=src/oui/kind.rs");
+    if env::var_os("CARGO_FEATURE_OUI_DB").is_none() {
+        return;
+    }
+    println!("cargo:rerun-if-env-changed=MAC_ADDR_OUI_DIR");
+    let dir = match env::var_os("MAC_ADDR_OUI_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("data"),
+    };
+
+    // Organization names, deduplicated, concatenated into one pool.
+    let mut pool = String::new();
+    let mut name_ends: Vec<u32> = Vec::new();
+    let mut name_index: HashMap<String, u32> = HashMap::new();
+    // (key, registry and name index) per prefix length: 24, 28 and 36 bits.
+    let mut blocks: [Vec<(u64, u32)>; 3] = [Vec::new(), Vec::new(), Vec::new()];
+
+    for file in FILES {
+        let path = dir.join(file);
+        println!("cargo:rerun-if-changed={}", path.display());
+        let Ok(text) = fs::read_to_string(&path) else {
+            continue;
+        };
+        for (i, line) in text.lines().enumerate().skip(1) {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (registry, network, name) = match parse_row(line) {
+                Ok(row) => row,
+                Err(msg) => {
+                    println!(
+                        "cargo:warning={}:{}: {}; row skipped",
+                        path.display(),
+                        i + 1,
+                        msg
+                    );
+                    continue;
+                }
+            };
+            let idx = *name_index.entry(name).or_insert_with_key(|name| {
+                pool.push_str(name);
+                name_ends.push(pool.len() as u32);
+                (name_ends.len() - 1) as u32
+            });
+            let bits = registry.prefix_len();
+            let slot = match bits {
+                24 => 0,
+                28 => 1,
+                _ => 2,
+            };
+            // The registry tag relies on declaration order; see `db::registry`.
+            blocks[slot].push((network >> (48 - bits as u32), idx << 3 | registry as u32));
+        }
+    }
+
+    let mut out = String::new();
+    writeln!(out, "static NAME_POOL: &str = {:?};", pool).unwrap();
+    write_array(
+        &mut out,
+        "NAME_ENDS",
+        "u32",
+        name_ends.iter().map(|e| e.to_string()),
+    );
+    for (slot, (suffix, key_ty)) in [("24", "u32"), ("28", "u32"), ("36", "u64")]
+        .into_iter()
+        .enumerate()
+    {
+        let entries = &mut blocks[slot];
+        entries.sort_by_key(|e| e.0);
+        entries.dedup_by_key(|e| e.0);
+        write_array(
+            &mut out,
+            &format!("KEYS_{}", suffix),
+            key_ty,
+            entries.iter().map(|e| format!("0x{:x}", e.0)),
+        );
+        write_array(
+            &mut out,
+            &format!("VALUES_{}", suffix),
+            "u32",
+            entries.iter().map(|e| e.1.to_string()),
+        );
+    }
+
+    let out_path = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("oui_db.rs");
+    fs::write(out_path, out).unwrap();
+}
+
+/// Parses one registry row into its registry, 48-bit network and organization name.
+fn parse_row(line: &str) -> Result<(Registry, u64, String), String> {
+    let fields = csv::split_fields(line).ok_or("unterminated quote")?;
+    if fields.len() < 3 {
+        return Err("expected at least 3 fields".into());
+    }
+    let registry = Registry::from_name(&csv::unquote(fields[0]))
+        .ok_or_else(|| format!("unknown registry {:?}", fields[0]))?;
+    let (network, bits) = csv::parse_assignment(&csv::unquote(fields[1]))
+        .ok_or_else(|| format!("invalid assignment {:?}", fields[1]))?;
+    if bits != registry.prefix_len() {
+        return Err(format!(
+            "{}-bit assignment in a {}-bit registry",
+            bits,
+            registry.prefix_len()
+        ));
+    }
+    Ok((registry, network, csv::unquote(fields[2])))
+}
+
+/// Writes a static array declaration with one item per line.
+fn write_array(
+    out: &mut String,
+    name: &str,
+    ty: &str,
+    items: impl ExactSizeIterator<Item = String>,
+) {
+    writeln!(out, "static {}: [{}; {}] = [", name, ty, items.len()).unwrap();
+    for item in items {
+        writeln!(out, "{},", item).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
@@ -0,0 +1,17 @@
+#!/usr/bin/env bash
+# Downloads the IEEE registry CSV files used by the `oui-db` feature into data/.
+set -euo pipefail
+
+dir="$(cd "$(dirname "$0")/.." && pwd)/data"
+base="https://standards-oui.ieee.org"
+
+fetch() {
+    echo "fetching $1 -> $dir/$2"
+    curl -fsSL "$base/$1" -o "$dir/$2"
+}
+
+fetch oui/oui.csv oui.csv
+fetch oui28/mam.csv mam.csv
+fetch oui36/oui36.csv oui36.csv
+fetch cid/cid.csv cid.csv
+fetch iab/iab.csv iab.csv
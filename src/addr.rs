@@ -5,11 +5,16 @@ extern crate alloc;
 use alloc::format;
 
 use crate::error::ParseMacAddrError;
+use crate::format::{FormattedMac, MacFormat};
+use crate::hw::HwAddr;
+#[cfg(feature = "oui-db")]
+use crate::oui::{self, Organization};
+use crate::parse::{self, MacNotation, ParseMode};
 use core::fmt;
 use core::str::FromStr;
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc as alloc_mod;
@@ -19,6 +24,9 @@ use std as alloc_mod;
 #[cfg(any(feature = "std", feature = "alloc"))]
 use alloc_mod::string::String;
 
+/// Largest value representable by a 48-bit address.
+const MAX_U48: u64 = 0xffff_ffff_ffff;
+
 /// 48-bit MAC address (IEEE EUI-48).
 #[repr(C)]
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Debug)]
@@ -27,13 +35,13 @@ pub struct MacAddr(pub u8, pub u8, pub u8, pub u8, pub u8, pub u8);
 impl MacAddr {
     /// Constructs a new [`MacAddr`] from six octets.
     #[inline]
-    pub fn new(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8) -> MacAddr {
+    pub const fn new(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8) -> MacAddr {
         MacAddr(a, b, c, d, e, f)
     }
 
     /// Constructs from a `[u8; 6]` array.
     #[inline]
-    pub fn from_octets(octets: [u8; 6]) -> MacAddr {
+    pub const fn from_octets(octets: [u8; 6]) -> MacAddr {
         MacAddr(
             octets[0], octets[1], octets[2], octets[3], octets[4], octets[5],
         )
@@ -41,10 +49,31 @@ impl MacAddr {
 
     /// Returns the 6 octets backing this address.
     #[inline]
-    pub fn octets(&self) -> [u8; 6] {
+    pub const fn octets(&self) -> [u8; 6] {
         [self.0, self.1, self.2, self.3, self.4, self.5]
     }
 
+    /// Returns a [`fmt::Display`] adapter that renders the address using `format`.
+    #[inline]
+    pub fn display_with(&self, format: MacFormat) -> FormattedMac<6> {
+        FormattedMac {
+            octets: self.octets(),
+            format,
+        }
+    }
+
+    /// Formats the address into `buf` without allocating.
+    ///
+    /// Fails with [`fmt::Error`] if `buf` is too small.
+    #[inline]
+    pub fn format_into<'a>(
+        &self,
+        format: &MacFormat,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, fmt::Error> {
+        format.write_to_buf(&self.octets(), buf)
+    }
+
     /// Returns a colon-separated lowercase hex string (`xx:xx:xx:xx:xx:xx`).
     #[cfg(any(feature = "std", feature = "alloc"))]
     #[inline]
@@ -57,13 +86,13 @@ impl MacAddr {
 
     /// Returns the all-zeros address.
     #[inline]
-    pub fn zero() -> MacAddr {
+    pub const fn zero() -> MacAddr {
         MacAddr(0, 0, 0, 0, 0, 0)
     }
 
     /// Returns the broadcast address (`ff:ff:ff:ff:ff:ff`).
     #[inline]
-    pub fn broadcast() -> MacAddr {
+    pub const fn broadcast() -> MacAddr {
         MacAddr(0xff, 0xff, 0xff, 0xff, 0xff, 0xff)
     }
 
@@ -83,143 +112,210 @@ impl MacAddr {
         MacAddr(o1, o2, o3, o4, o5, o6)
     }
 
+    /// Parses a MAC address in any common notation and reports which one was used.
+    ///
+    /// Accepts colon (`00:25:96:12:34:56`), hyphen (`00-25-96-12-34-56`),
+    /// space (`00 25 96 12 34 56`), Cisco dotted (`0025.9612.3456`) and
+    /// bare (`002596123456`) forms. The separator must be consistent.
     #[inline]
-    pub fn is_broadcast(&self) -> bool {
-        self.0 == 0xff
-            && self.1 == 0xff
-            && self.2 == 0xff
-            && self.3 == 0xff
-            && self.4 == 0xff
-            && self.5 == 0xff
+    pub const fn parse_with_notation(s: &str) -> Result<(MacAddr, MacNotation), ParseMacAddrError> {
+        match parse::parse_octets::<6>(s, ParseMode::Standard) {
+            Ok((octets, notation)) => Ok((MacAddr::from_octets(octets), notation)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Parses any notation accepted by [`MacAddr::parse_with_notation`] under the given [`ParseMode`].
+    #[inline]
+    pub const fn parse_with_mode(s: &str, mode: ParseMode) -> Result<MacAddr, ParseMacAddrError> {
+        match parse::parse_octets::<6>(s, mode) {
+            Ok((octets, _)) => Ok(MacAddr::from_octets(octets)),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[inline]
+    pub const fn is_broadcast(&self) -> bool {
+        HwAddr::new(self.octets()).is_broadcast()
     }
 
     /// Returns `true` if the address is multicast.
     #[inline]
-    pub fn is_multicast(&self) -> bool {
-        self.0 & 0x01 == 0x01
+    pub const fn is_multicast(&self) -> bool {
+        HwAddr::new(self.octets()).is_multicast()
     }
 
     /// Returns `true` if the address is unicast.
     #[inline]
-    pub fn is_unicast(&self) -> bool {
-        !self.is_multicast() && !self.is_broadcast()
+    pub const fn is_unicast(&self) -> bool {
+        HwAddr::new(self.octets()).is_unicast()
     }
 
     /// Returns `true` if the address is locally administered.
     #[inline]
-    pub fn is_locally_administered(&self) -> bool {
-        self.0 & 0x02 == 0x02
+    pub const fn is_locally_administered(&self) -> bool {
+        HwAddr::new(self.octets()).is_locally_administered()
     }
 
     /// Returns `true` if the address is universally administered.
     #[inline]
-    pub fn is_universal(&self) -> bool {
-        !self.is_locally_administered()
+    pub const fn is_universal(&self) -> bool {
+        HwAddr::new(self.octets()).is_universal()
     }
 
     /// Returns the OUI (first 3 octets).
     #[inline]
-    pub fn oui(&self) -> [u8; 3] {
+    pub const fn oui(&self) -> [u8; 3] {
         [self.0, self.1, self.2]
     }
+
+    /// Returns the organization holding the most specific IEEE assignment
+    /// (MA-S/IAB, then MA-M, then MA-L/CID) that covers this address.
+    #[cfg(feature = "oui-db")]
+    #[inline]
+    pub fn vendor(&self) -> Option<Organization<'static>> {
+        oui::lookup(*self)
+    }
+
+    /// Returns the address as an integer, first octet most significant.
+    ///
+    /// The result always fits in the low 48 bits.
+    #[inline]
+    pub const fn to_u64(&self) -> u64 {
+        let [a, b, c, d, e, f] = self.octets();
+        u64::from_be_bytes([0, 0, a, b, c, d, e, f])
+    }
+
+    /// Constructs from the low 48 bits of `v`, first octet most significant.
+    ///
+    /// The upper 16 bits are ignored; use [`TryFrom<u64>`] to reject them instead.
+    #[inline]
+    pub const fn from_u64(v: u64) -> MacAddr {
+        let [_, _, a, b, c, d, e, f] = v.to_be_bytes();
+        MacAddr(a, b, c, d, e, f)
+    }
+
+    /// Adds `n`, returning `None` if the result exceeds `ff:ff:ff:ff:ff:ff`.
+    #[inline]
+    pub const fn checked_add(&self, n: u64) -> Option<MacAddr> {
+        match self.to_u64().checked_add(n) {
+            Some(v) if v <= MAX_U48 => Some(MacAddr::from_u64(v)),
+            _ => None,
+        }
+    }
+
+    /// Subtracts `n`, returning `None` if the result is below `00:00:00:00:00:00`.
+    #[inline]
+    pub const fn checked_sub(&self, n: u64) -> Option<MacAddr> {
+        match self.to_u64().checked_sub(n) {
+            Some(v) => Some(MacAddr::from_u64(v)),
+            None => None,
+        }
+    }
+
+    /// Adds `n`, wrapping around at the 48-bit boundary.
+    #[inline]
+    pub const fn wrapping_add(&self, n: u64) -> MacAddr {
+        MacAddr::from_u64(self.to_u64().wrapping_add(n))
+    }
+
+    /// Subtracts `n`, wrapping around at the 48-bit boundary.
+    #[inline]
+    pub const fn wrapping_sub(&self, n: u64) -> MacAddr {
+        MacAddr::from_u64(self.to_u64().wrapping_sub(n))
+    }
+
+    /// Adds `n`, stopping at `ff:ff:ff:ff:ff:ff`.
+    #[inline]
+    pub const fn saturating_add(&self, n: u64) -> MacAddr {
+        match self.checked_add(n) {
+            Some(m) => m,
+            None => MacAddr::broadcast(),
+        }
+    }
+
+    /// Subtracts `n`, stopping at `00:00:00:00:00:00`.
+    #[inline]
+    pub const fn saturating_sub(&self, n: u64) -> MacAddr {
+        MacAddr::from_u64(self.to_u64().saturating_sub(n))
+    }
+
+    /// Returns a mask with the first `prefix_len` bits set, or `None` if `prefix_len > 48`.
+    #[inline]
+    pub const fn mask_from_prefix_len(prefix_len: u8) -> Option<MacAddr> {
+        if prefix_len > 48 {
+            return None;
+        }
+        let host = match MAX_U48.checked_shr(prefix_len as u32) {
+            Some(h) => h,
+            None => 0,
+        };
+        Some(MacAddr::from_u64(MAX_U48 & !host))
+    }
+
+    /// Returns the prefix length if this address is a contiguous mask such as `ff:ff:ff:f0:00:00`.
+    ///
+    /// Returns `None` for non-contiguous masks.
+    #[inline]
+    pub const fn prefix_len_of_mask(&self) -> Option<u8> {
+        let bits = self.to_u64() << 16;
+        let len = bits.leading_ones();
+        if len + bits.trailing_zeros() == 64 {
+            Some(len as u8)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if this address equals `value` in every bit set in `mask`.
+    #[inline]
+    pub const fn matches_masked(&self, value: MacAddr, mask: MacAddr) -> bool {
+        let m = mask.to_u64();
+        self.to_u64() & m == value.to_u64() & m
+    }
 }
 
 impl fmt::Display for MacAddr {
     /// Lowercase hex with `:` separators.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let _ = write!(
-            f,
-            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
-            self.0, self.1, self.2, self.3, self.4, self.5
-        );
-        Ok(())
+        MacFormat::new().write(&self.octets(), f)
     }
 }
 
 impl fmt::LowerHex for MacAddr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
-            self.0, self.1, self.2, self.3, self.4, self.5
-        )
+        MacFormat::new().write(&self.octets(), f)
     }
 }
 
 impl fmt::UpperHex for MacAddr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
-            self.0, self.1, self.2, self.3, self.4, self.5
-        )
+        MacFormat::new().uppercase(true).write(&self.octets(), f)
     }
 }
 
 impl FromStr for MacAddr {
     type Err = ParseMacAddrError;
 
+    /// Parses any notation accepted by [`MacAddr::parse_with_notation`].
     fn from_str(s: &str) -> Result<MacAddr, ParseMacAddrError> {
-        let mut parts = [0u8; 6];
-        let mut i = 0;
-        for split in s.split(':') {
-            if i == 6 {
-                return Err(ParseMacAddrError::TooManyComponents);
-            }
-            match u8::from_str_radix(split, 16) {
-                Ok(b) if !split.is_empty() => parts[i] = b,
-                _ => return Err(ParseMacAddrError::InvalidComponent),
-            }
-            i += 1;
-        }
-        if i == 6 {
-            Ok(MacAddr(
-                parts[0], parts[1], parts[2], parts[3], parts[4], parts[5],
-            ))
-        } else {
-            Err(ParseMacAddrError::TooFewComponents)
-        }
+        MacAddr::parse_with_notation(s).map(|(mac, _)| mac)
     }
 }
 
 #[cfg(feature = "serde")]
 impl Serialize for MacAddr {
+    #[inline]
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        if serializer.is_human_readable() {
-            serializer.collect_str(self)
-        } else {
-            serializer.serialize_bytes(&[self.0, self.1, self.2, self.3, self.4, self.5])
-        }
+        HwAddr::new(self.octets()).serialize(serializer)
     }
 }
 
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for MacAddr {
+    #[inline]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        struct MacAddrVisitor;
-        impl<'de> de::Visitor<'de> for MacAddrVisitor {
-            type Value = MacAddr;
-
-            fn visit_str<E: de::Error>(self, value: &str) -> Result<MacAddr, E> {
-                value.parse().map_err(E::custom)
-            }
-            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<MacAddr, E> {
-                if v.len() == 6 {
-                    Ok(MacAddr::new(v[0], v[1], v[2], v[3], v[4], v[5]))
-                } else {
-                    Err(E::invalid_length(v.len(), &self))
-                }
-            }
-            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                f.write_str("either a string MAC address or 6-byte array")
-            }
-        }
-
-        if deserializer.is_human_readable() {
-            deserializer.deserialize_str(MacAddrVisitor)
-        } else {
-            deserializer.deserialize_bytes(MacAddrVisitor)
-        }
+        HwAddr::<6>::deserialize(deserializer).map(MacAddr::from)
     }
 }
 
@@ -237,19 +333,36 @@ impl From<MacAddr> for [u8; 6] {
     }
 }
 
-impl TryFrom<&[u8]> for MacAddr {
+impl From<MacAddr> for u64 {
+    #[inline]
+    fn from(m: MacAddr) -> Self {
+        m.to_u64()
+    }
+}
+
+impl TryFrom<u64> for MacAddr {
     type Error = ();
 
+    /// Fails if any of the upper 16 bits are set.
     #[inline]
-    fn try_from(s: &[u8]) -> Result<Self, Self::Error> {
-        if s.len() == 6 {
-            Ok(MacAddr::new(s[0], s[1], s[2], s[3], s[4], s[5]))
+    fn try_from(v: u64) -> Result<Self, Self::Error> {
+        if v <= MAX_U48 {
+            Ok(MacAddr::from_u64(v))
         } else {
             Err(())
         }
     }
 }
 
+impl TryFrom<&[u8]> for MacAddr {
+    type Error = ();
+
+    #[inline]
+    fn try_from(s: &[u8]) -> Result<Self, Self::Error> {
+        HwAddr::<6>::try_from(s).map(MacAddr::from)
+    }
+}
+
 impl AsRef<[u8; 6]> for MacAddr {
     /// # Safety
     /// This is a plain `repr(Rust)` tuple struct of six `u8`s.
@@ -6,11 +6,16 @@ use alloc::format;
 
 use crate::addr::MacAddr;
 use crate::error::ParseMacAddrError;
+use crate::format::{FormattedMac, MacFormat};
+use crate::hw::HwAddr;
+#[cfg(feature = "oui-db")]
+use crate::oui::{self, Organization};
+use crate::parse::{self, MacNotation, ParseMode};
 use core::fmt;
 use core::str::FromStr; // EUI-48
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc as alloc_mod;
@@ -36,14 +41,15 @@ pub struct MacAddr8(
 
 impl MacAddr8 {
     /// Constructs a new [`MacAddr8`] from eight octets.
+    #[allow(clippy::too_many_arguments)]
     #[inline]
-    pub fn new(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8, h: u8) -> MacAddr8 {
+    pub const fn new(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8, h: u8) -> MacAddr8 {
         MacAddr8(a, b, c, d, e, f, g, h)
     }
 
     /// Constructs from a `[u8; 8]` array.
     #[inline]
-    pub fn from_octets(octets: [u8; 8]) -> MacAddr8 {
+    pub const fn from_octets(octets: [u8; 8]) -> MacAddr8 {
         MacAddr8(
             octets[0], octets[1], octets[2], octets[3], octets[4], octets[5], octets[6], octets[7],
         )
@@ -51,12 +57,33 @@ impl MacAddr8 {
 
     /// Returns the 8 octets backing this address.
     #[inline]
-    pub fn octets(&self) -> [u8; 8] {
+    pub const fn octets(&self) -> [u8; 8] {
         [
             self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7,
         ]
     }
 
+    /// Returns a [`fmt::Display`] adapter that renders the address using `format`.
+    #[inline]
+    pub fn display_with(&self, format: MacFormat) -> FormattedMac<8> {
+        FormattedMac {
+            octets: self.octets(),
+            format,
+        }
+    }
+
+    /// Formats the address into `buf` without allocating.
+    ///
+    /// Fails with [`fmt::Error`] if `buf` is too small.
+    #[inline]
+    pub fn format_into<'a>(
+        &self,
+        format: &MacFormat,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, fmt::Error> {
+        format.write_to_buf(&self.octets(), buf)
+    }
+
     /// Returns a colon-separated lowercase hex string (`xx:xx:xx:xx:xx:xx:xx:xx`).
     #[cfg(any(feature = "std", feature = "alloc"))]
     #[inline]
@@ -67,47 +94,169 @@ impl MacAddr8 {
         )
     }
 
+    /// Parses an EUI-64 address in any common notation and reports which one was used.
+    ///
+    /// Accepts colon, hyphen, space, Cisco dotted (`0025.96ff.fe12.3456`)
+    /// and bare (`002596fffe123456`) forms. The separator must be consistent.
+    #[inline]
+    pub const fn parse_with_notation(
+        s: &str,
+    ) -> Result<(MacAddr8, MacNotation), ParseMacAddrError> {
+        match parse::parse_octets::<8>(s, ParseMode::Standard) {
+            Ok((octets, notation)) => Ok((MacAddr8::from_octets(octets), notation)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Parses any notation accepted by [`MacAddr8::parse_with_notation`] under the given [`ParseMode`].
+    #[inline]
+    pub const fn parse_with_mode(s: &str, mode: ParseMode) -> Result<MacAddr8, ParseMacAddrError> {
+        match parse::parse_octets::<8>(s, mode) {
+            Ok((octets, _)) => Ok(MacAddr8::from_octets(octets)),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Returns `true` if the address is multicast.
     #[inline]
-    pub fn is_multicast(&self) -> bool {
-        self.0 & 0x01 == 0x01
+    pub const fn is_multicast(&self) -> bool {
+        HwAddr::new(self.octets()).is_multicast()
     }
 
     /// Returns `true` if the address is unicast.
     #[inline]
-    pub fn is_unicast(&self) -> bool {
-        !self.is_multicast()
+    pub const fn is_unicast(&self) -> bool {
+        HwAddr::new(self.octets()).is_unicast()
     }
 
     /// Returns `true` if the address is locally administered.
     #[inline]
-    pub fn is_locally_administered(&self) -> bool {
-        self.0 & 0x02 == 0x02
+    pub const fn is_locally_administered(&self) -> bool {
+        HwAddr::new(self.octets()).is_locally_administered()
     }
 
     /// Returns `true` if the address is universally administered.
     #[inline]
-    pub fn is_universal(&self) -> bool {
-        !self.is_locally_administered()
+    pub const fn is_universal(&self) -> bool {
+        HwAddr::new(self.octets()).is_universal()
     }
 
     /// Returns the OUI (first 3 octets).
     #[inline]
-    pub fn oui(&self) -> [u8; 3] {
+    pub const fn oui(&self) -> [u8; 3] {
         [self.0, self.1, self.2]
     }
 
+    /// Returns the organization holding the most specific IEEE assignment
+    /// (MA-S/IAB, then MA-M, then MA-L/CID) that covers this address.
+    ///
+    /// Only the first 48 bits take part in the lookup.
+    #[cfg(feature = "oui-db")]
+    #[inline]
+    pub fn vendor(&self) -> Option<Organization<'static>> {
+        oui::lookup(MacAddr::new(self.0, self.1, self.2, self.3, self.4, self.5))
+    }
+
+    /// Returns the address as an integer, first octet most significant.
+    #[inline]
+    pub const fn to_u64(&self) -> u64 {
+        u64::from_be_bytes(self.octets())
+    }
+
+    /// Constructs from an integer, first octet most significant.
+    #[inline]
+    pub const fn from_u64(v: u64) -> MacAddr8 {
+        MacAddr8::from_octets(v.to_be_bytes())
+    }
+
+    /// Adds `n`, returning `None` on overflow.
+    #[inline]
+    pub const fn checked_add(&self, n: u64) -> Option<MacAddr8> {
+        match self.to_u64().checked_add(n) {
+            Some(v) => Some(MacAddr8::from_u64(v)),
+            None => None,
+        }
+    }
+
+    /// Subtracts `n`, returning `None` on underflow.
+    #[inline]
+    pub const fn checked_sub(&self, n: u64) -> Option<MacAddr8> {
+        match self.to_u64().checked_sub(n) {
+            Some(v) => Some(MacAddr8::from_u64(v)),
+            None => None,
+        }
+    }
+
+    /// Adds `n`, wrapping around at the 64-bit boundary.
+    #[inline]
+    pub const fn wrapping_add(&self, n: u64) -> MacAddr8 {
+        MacAddr8::from_u64(self.to_u64().wrapping_add(n))
+    }
+
+    /// Subtracts `n`, wrapping around at the 64-bit boundary.
+    #[inline]
+    pub const fn wrapping_sub(&self, n: u64) -> MacAddr8 {
+        MacAddr8::from_u64(self.to_u64().wrapping_sub(n))
+    }
+
+    /// Adds `n`, stopping at `ff:ff:ff:ff:ff:ff:ff:ff`.
+    #[inline]
+    pub const fn saturating_add(&self, n: u64) -> MacAddr8 {
+        MacAddr8::from_u64(self.to_u64().saturating_add(n))
+    }
+
+    /// Subtracts `n`, stopping at `00:00:00:00:00:00:00:00`.
+    #[inline]
+    pub const fn saturating_sub(&self, n: u64) -> MacAddr8 {
+        MacAddr8::from_u64(self.to_u64().saturating_sub(n))
+    }
+
+    /// Returns a mask with the first `prefix_len` bits set, or `None` if `prefix_len > 64`.
+    #[inline]
+    pub const fn mask_from_prefix_len(prefix_len: u8) -> Option<MacAddr8> {
+        if prefix_len > 64 {
+            return None;
+        }
+        let host = match u64::MAX.checked_shr(prefix_len as u32) {
+            Some(h) => h,
+            None => 0,
+        };
+        Some(MacAddr8::from_u64(!host))
+    }
+
+    /// Returns the prefix length if this address is a contiguous mask such as `ff:ff:ff:f0:00:00:00:00`.
+    ///
+    /// Returns `None` for non-contiguous masks.
+    #[inline]
+    pub const fn prefix_len_of_mask(&self) -> Option<u8> {
+        let bits = self.to_u64();
+        let len = bits.leading_ones();
+        if len + bits.trailing_zeros() == 64 {
+            Some(len as u8)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if this address equals `value` in every bit set in `mask`.
+    #[inline]
+    pub const fn matches_masked(&self, value: MacAddr8, mask: MacAddr8) -> bool {
+        let m = mask.to_u64();
+        self.to_u64() & m == value.to_u64() & m
+    }
+
     /// Converts from EUI-48 (`MacAddr`) to EUI-64 by inserting `ff:fe`.
-    /// Commonly used for IPv6 IID formation from MAC addresses.
+    /// The U/L bit is kept; IPv6 interface identifiers use
+    /// [`MacAddr::to_modified_eui64`] instead.
     #[inline]
-    pub fn from_eui48(mac: MacAddr) -> MacAddr8 {
+    pub const fn from_eui48(mac: MacAddr) -> MacAddr8 {
         let [a, b, c, d, e, f] = mac.octets();
         MacAddr8(a, b, c, 0xff, 0xfe, d, e, f)
     }
 
     /// Converts to EUI-48 if the EUI-64 matches the `ff:fe` embedding pattern.
     #[inline]
-    pub fn to_eui48(&self) -> Option<MacAddr> {
+    pub const fn to_eui48(&self) -> Option<MacAddr> {
         if self.3 == 0xff && self.4 == 0xfe {
             Some(MacAddr::new(self.0, self.1, self.2, self.5, self.6, self.7))
         } else {
@@ -119,103 +268,44 @@ impl MacAddr8 {
 impl fmt::Display for MacAddr8 {
     /// Lowercase hex with `:` separators.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let _ = write!(
-            f,
-            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
-            self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7
-        );
-        Ok(())
+        MacFormat::new().write(&self.octets(), f)
     }
 }
 
 impl fmt::LowerHex for MacAddr8 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
-            self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7
-        )
+        MacFormat::new().write(&self.octets(), f)
     }
 }
 
 impl fmt::UpperHex for MacAddr8 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
-            self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7
-        )
+        MacFormat::new().uppercase(true).write(&self.octets(), f)
     }
 }
 
 impl FromStr for MacAddr8 {
     type Err = ParseMacAddrError;
 
+    /// Parses any notation accepted by [`MacAddr8::parse_with_notation`].
     fn from_str(s: &str) -> Result<MacAddr8, ParseMacAddrError> {
-        let mut parts = [0u8; 8];
-        let mut i = 0;
-        for split in s.split(':') {
-            if i == 8 {
-                return Err(ParseMacAddrError::TooManyComponents);
-            }
-            match u8::from_str_radix(split, 16) {
-                Ok(b) if !split.is_empty() => parts[i] = b,
-                _ => return Err(ParseMacAddrError::InvalidComponent),
-            }
-            i += 1;
-        }
-        if i == 8 {
-            Ok(MacAddr8(
-                parts[0], parts[1], parts[2], parts[3], parts[4], parts[5], parts[6], parts[7],
-            ))
-        } else {
-            Err(ParseMacAddrError::TooFewComponents)
-        }
+        MacAddr8::parse_with_notation(s).map(|(mac, _)| mac)
     }
 }
 
 #[cfg(feature = "serde")]
 impl Serialize for MacAddr8 {
+    #[inline]
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        if serializer.is_human_readable() {
-            serializer.collect_str(self)
-        } else {
-            serializer.serialize_bytes(&[
-                self.0, self.1, self.2, self.3, self.4, self.5, self.6, self.7,
-            ])
-        }
+        HwAddr::new(self.octets()).serialize(serializer)
     }
 }
 
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for MacAddr8 {
+    #[inline]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        struct MacAddr8Visitor;
-        impl<'de> de::Visitor<'de> for MacAddr8Visitor {
-            type Value = MacAddr8;
-
-            fn visit_str<E: de::Error>(self, value: &str) -> Result<MacAddr8, E> {
-                value.parse().map_err(E::custom)
-            }
-            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<MacAddr8, E> {
-                if v.len() == 8 {
-                    Ok(MacAddr8::new(
-                        v[0], v[1], v[2], v[3], v[4], v[5], v[6], v[7],
-                    ))
-                } else {
-                    Err(E::invalid_length(v.len(), &self))
-                }
-            }
-            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                f.write_str("either a string EUI-64 address or 8-byte array")
-            }
-        }
-
-        if deserializer.is_human_readable() {
-            deserializer.deserialize_str(MacAddr8Visitor)
-        } else {
-            deserializer.deserialize_bytes(MacAddr8Visitor)
-        }
+        HwAddr::<8>::deserialize(deserializer).map(MacAddr8::from)
     }
 }
 
@@ -233,18 +323,26 @@ impl From<MacAddr8> for [u8; 8] {
     }
 }
 
+impl From<u64> for MacAddr8 {
+    #[inline]
+    fn from(v: u64) -> Self {
+        MacAddr8::from_u64(v)
+    }
+}
+
+impl From<MacAddr8> for u64 {
+    #[inline]
+    fn from(m: MacAddr8) -> Self {
+        m.to_u64()
+    }
+}
+
 impl TryFrom<&[u8]> for MacAddr8 {
     type Error = ();
 
     #[inline]
     fn try_from(s: &[u8]) -> Result<Self, Self::Error> {
-        if s.len() == 8 {
-            Ok(MacAddr8::new(
-                s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7],
-            ))
-        } else {
-            Err(())
-        }
+        HwAddr::<8>::try_from(s).map(MacAddr8::from)
     }
 }
 
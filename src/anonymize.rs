@@ -0,0 +1,202 @@
+use crate::addr::MacAddr;
+use crate::addr8::MacAddr8;
+use crate::format::MacFormat;
+use crate::int::MacInteger;
+use crate::siphash::{self, SipHasher24};
+use core::fmt;
+
+/// Number of Feistel rounds used by [`Anonymizer::permute`].
+const FEISTEL_ROUNDS: u8 = 8;
+
+/// Key derivation key, distinct from the one used by [`MacAddr::derive_stable`].
+const KEY_DERIVATION_KEY: [u8; 16] = *b"mac-addr.anonym1";
+
+/// Domain separation bytes for the different hash uses.
+const DOMAIN_PSEUDONYM: u8 = b'p';
+const DOMAIN_PSEUDONYM_NIC: u8 = b'n';
+const DOMAIN_FEISTEL: u8 = b'f';
+
+/// Keyed pseudonymization of MAC addresses for logs and exports.
+///
+/// All methods work on both [`MacAddr`] and [`MacAddr8`] and never allocate.
+/// The same key always maps an address to the same pseudonym, so records can
+/// still be correlated, while the real address cannot be recovered without
+/// the key. Hashing uses SipHash-2-4 under a key derived from the secret.
+///
+/// ```
+/// use mac_addr::{Anonymizer, MacAddr};
+///
+/// let anon = Anonymizer::new(b"log-pepper");
+/// let mac = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+///
+/// let p = anon.pseudonymize_keep_oui(mac);
+/// assert_eq!(p.oui(), mac.oui());
+///
+/// let q = anon.permute(mac);
+/// assert_eq!(anon.unpermute(q), mac);
+/// ```
+#[derive(Clone)]
+pub struct Anonymizer {
+    key: [u8; 16],
+}
+
+impl Anonymizer {
+    /// Creates an anonymizer keyed with `secret`, which may be any length.
+    pub fn new(secret: &[u8]) -> Anonymizer {
+        Anonymizer {
+            key: siphash::derive_key(KEY_DERIVATION_KEY, secret),
+        }
+    }
+
+    fn hash(&self, domain: u8, bits: u32, round: u8, value: u64) -> u64 {
+        let mut h = SipHasher24::new(self.key);
+        h.write(&[domain, bits as u8, round]);
+        h.write(&value.to_be_bytes());
+        h.finish()
+    }
+
+    /// Replaces the whole address with a keyed hash.
+    ///
+    /// The I/G bit of `mac` is kept and the U/L bit is set, so pseudonyms never
+    /// collide with a vendor-assigned address. Distinct inputs may collide; use
+    /// [`Anonymizer::permute`] when that matters.
+    pub fn pseudonymize<A: MacInteger>(&self, mac: A) -> A {
+        let v = mac.to_u64();
+        let group = 1u64 << (A::BITS - 8);
+        let local = group << 1;
+        let h = self.hash(DOMAIN_PSEUDONYM, A::BITS, 0, v) & A::MAX;
+        A::from_u64((h & !group) | (v & group) | local)
+    }
+
+    /// Keeps the OUI and replaces the remaining octets with a keyed hash.
+    ///
+    /// The vendor stays visible while the NIC-specific part is hidden.
+    pub fn pseudonymize_keep_oui<A: MacInteger>(&self, mac: A) -> A {
+        let v = mac.to_u64();
+        let nic = A::MAX >> 24;
+        let h = self.hash(DOMAIN_PSEUDONYM_NIC, A::BITS, 0, v);
+        A::from_u64((v & !nic) | (h & nic))
+    }
+
+    /// Format-preserving pseudonymization: a keyed permutation of the address space.
+    ///
+    /// Distinct inputs always map to distinct outputs under one key, and
+    /// [`Anonymizer::unpermute`] reverses the mapping. The output is an
+    /// arbitrary address of the same width, so the I/G and U/L bits are not preserved.
+    #[inline]
+    pub fn permute<A: MacInteger>(&self, mac: A) -> A {
+        A::from_u64(self.feistel(mac.to_u64(), A::BITS, false))
+    }
+
+    /// Inverts [`Anonymizer::permute`].
+    #[inline]
+    pub fn unpermute<A: MacInteger>(&self, mac: A) -> A {
+        A::from_u64(self.feistel(mac.to_u64(), A::BITS, true))
+    }
+
+    /// Like [`Anonymizer::permute`], but keeps the OUI and permutes only the remaining octets.
+    #[inline]
+    pub fn permute_keep_oui<A: MacInteger>(&self, mac: A) -> A {
+        let v = mac.to_u64();
+        let nic = A::MAX >> 24;
+        A::from_u64((v & !nic) | self.feistel(v & nic, A::BITS - 24, false))
+    }
+
+    /// Inverts [`Anonymizer::permute_keep_oui`].
+    #[inline]
+    pub fn unpermute_keep_oui<A: MacInteger>(&self, mac: A) -> A {
+        let v = mac.to_u64();
+        let nic = A::MAX >> 24;
+        A::from_u64((v & !nic) | self.feistel(v & nic, A::BITS - 24, true))
+    }
+
+    /// Balanced Feistel network over the low `bits` bits of `v` (`bits` is even).
+    fn feistel(&self, v: u64, bits: u32, inverse: bool) -> u64 {
+        let half = bits / 2;
+        let mask = (1u64 << half) - 1;
+        let mut l = (v >> half) & mask;
+        let mut r = v & mask;
+        for i in 0..FEISTEL_ROUNDS {
+            if inverse {
+                let round = FEISTEL_ROUNDS - 1 - i;
+                (l, r) = (r ^ (self.hash(DOMAIN_FEISTEL, bits, round, l) & mask), l);
+            } else {
+                (l, r) = (r, l ^ (self.hash(DOMAIN_FEISTEL, bits, i, r) & mask));
+            }
+        }
+        (l << half) | r
+    }
+}
+
+impl fmt::Debug for Anonymizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Anonymizer").finish_non_exhaustive()
+    }
+}
+
+/// [`fmt::Display`] adapter that hides trailing octets, returned by the
+/// `redacted` methods of [`MacAddr`], [`MacAddr8`] and [`HwAddr`](crate::HwAddr).
+///
+/// ```
+/// use mac_addr::{MacAddr, MacFormat, Separator};
+///
+/// let mac = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+/// assert_eq!(mac.redacted(3).to_string(), "00:25:96:xx:xx:xx");
+/// let dotted = MacFormat::new().separator(Separator::Dot).group_size(2);
+/// assert_eq!(mac.redacted(3).format(dotted).to_string(), "0025.96xx.xxxx");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Redacted<const N: usize> {
+    octets: [u8; N],
+    visible: usize,
+    format: MacFormat,
+}
+
+impl<const N: usize> Redacted<N> {
+    #[inline]
+    pub(crate) fn new(octets: [u8; N], visible: usize) -> Redacted<N> {
+        Redacted {
+            octets,
+            visible,
+            format: MacFormat::new(),
+        }
+    }
+
+    /// Renders using `format` instead of the default colon notation.
+    #[inline]
+    pub fn format(mut self, format: MacFormat) -> Redacted<N> {
+        self.format = format;
+        self
+    }
+}
+
+impl<const N: usize> fmt::Display for Redacted<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format.write_redacted(&self.octets, self.visible, f)
+    }
+}
+
+// Shows the masked form only, so `{:?}` never leaks the hidden octets.
+impl<const N: usize> fmt::Debug for Redacted<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Redacted")
+            .field(&format_args!("{}", self))
+            .finish()
+    }
+}
+
+impl MacAddr {
+    /// Returns a [`fmt::Display`] adapter that shows the first `visible` octets and masks the rest.
+    #[inline]
+    pub fn redacted(&self, visible: usize) -> Redacted<6> {
+        Redacted::new(self.octets(), visible)
+    }
+}
+
+impl MacAddr8 {
+    /// Returns a [`fmt::Display`] adapter that shows the first `visible` octets and masks the rest.
+    #[inline]
+    pub fn redacted(&self, visible: usize) -> Redacted<8> {
+        Redacted::new(self.octets(), visible)
+    }
+}
@@ -0,0 +1,47 @@
+use crate::addr::MacAddr;
+use crate::siphash::{self, SipHasher24};
+
+/// Key derivation key for [`MacAddr::derive_stable`].
+const KEY_DERIVATION_KEY: [u8; 16] = *b"mac-addr.stable1";
+
+/// Hashes `context` under a SipHash key derived from `key`.
+fn stable_hash(key: &[u8], context: &[u8]) -> [u8; 8] {
+    let mut h = SipHasher24::new(siphash::derive_key(KEY_DERIVATION_KEY, key));
+    h.write(context);
+    h.finish().to_be_bytes()
+}
+
+impl MacAddr {
+    /// Derives a locally administered unicast address from a secret `key` and a `context`.
+    ///
+    /// The same inputs always give the same address, so a machine ID as `key`
+    /// and an interface or network name as `context` yield a stable per-network
+    /// address without storing state. The 48 bits are the first six bytes of a
+    /// SipHash-2-4 output (big-endian), with the I/G bit cleared and the U/L bit
+    /// set. The SipHash key is itself derived from `key` as
+    /// `SipHash(K, 0x00 || key) || SipHash(K, 0x01 || key)` with the fixed key
+    /// `K = "mac-addr.stable1"`. The construction will not change within a
+    /// major version.
+    ///
+    /// ```
+    /// use mac_addr::MacAddr;
+    ///
+    /// let a = MacAddr::derive_stable(b"machine-id", b"eth0");
+    /// assert_eq!(a, MacAddr::derive_stable(b"machine-id", b"eth0"));
+    /// assert_ne!(a, MacAddr::derive_stable(b"machine-id", b"eth1"));
+    /// assert!(a.is_locally_administered() && a.is_unicast());
+    /// ```
+    pub fn derive_stable(key: &[u8], context: &[u8]) -> MacAddr {
+        let h = stable_hash(key, context);
+        MacAddr((h[0] & !0x01) | 0x02, h[1], h[2], h[3], h[4], h[5])
+    }
+
+    /// Like [`MacAddr::derive_stable`], but keeps `oui` and derives only the last three octets.
+    ///
+    /// The suffix is the first three bytes of the same hash. `oui` is used as
+    /// given, including its I/G and U/L bits.
+    pub fn derive_stable_with_oui(oui: [u8; 3], key: &[u8], context: &[u8]) -> MacAddr {
+        let h = stable_hash(key, context);
+        MacAddr(oui[0], oui[1], oui[2], h[0], h[1], h[2])
+    }
+}
@@ -1,26 +1,424 @@
 use core::fmt;
 
-/// Error returned when parsing a MAC address with [`core::str::FromStr`].
-#[derive(Copy, Debug, PartialEq, Eq, Clone)]
-pub enum ParseMacAddrError {
-    /// Input contained more than 6 components.
+/// The reason a MAC address string was rejected.
+#[derive(Copy, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum ParseErrorKind {
+    /// Input contained more components than the address has.
     TooManyComponents,
-    /// Input contained fewer than 6 components.
+    /// Input contained fewer components than the address has.
     TooFewComponents,
-    /// A component was invalid hex or empty.
+    /// A component was empty, not hex, or had the wrong number of digits.
     InvalidComponent,
+    /// A separator differed from the first one in the input.
+    MixedSeparators,
+    /// Input had leading or trailing whitespace.
+    UnexpectedWhitespace,
+}
+
+/// Error returned when parsing a MAC address with [`core::str::FromStr`].
+///
+/// Besides the [`ParseErrorKind`], it records where parsing stopped so that
+/// callers can point at the offending part of the input.
+#[derive(Copy, Debug, PartialEq, Eq, Clone, Hash)]
+pub struct ParseMacAddrError {
+    kind: ParseErrorKind,
+    offset: usize,
+    component: usize,
+    expected_len: usize,
+}
+
+impl ParseMacAddrError {
+    #[inline]
+    pub(crate) const fn new(
+        kind: ParseErrorKind,
+        offset: usize,
+        component: usize,
+        expected_len: usize,
+    ) -> ParseMacAddrError {
+        ParseMacAddrError {
+            kind,
+            offset,
+            component,
+            expected_len,
+        }
+    }
+
+    /// Returns the reason the input was rejected.
+    #[inline]
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset in the input at which the error was detected.
+    #[inline]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the zero-based index of the offending component.
+    #[inline]
+    pub const fn component(&self) -> usize {
+        self.component
+    }
+
+    /// Returns the number of octets the target address type expects (6 or 8).
+    #[inline]
+    pub const fn expected_len(&self) -> usize {
+        self.expected_len
+    }
 }
 
 impl fmt::Display for ParseMacAddrError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let s = match *self {
-            ParseMacAddrError::TooManyComponents => "Too many components in a MAC address string",
-            ParseMacAddrError::TooFewComponents => "Too few components in a MAC address string",
-            ParseMacAddrError::InvalidComponent => "Invalid component in a MAC address string",
+        let s = match self.kind {
+            ParseErrorKind::TooManyComponents => "Too many components in a MAC address string",
+            ParseErrorKind::TooFewComponents => "Too few components in a MAC address string",
+            ParseErrorKind::InvalidComponent => "Invalid component in a MAC address string",
+            ParseErrorKind::MixedSeparators => "Mixed separators in a MAC address string",
+            ParseErrorKind::UnexpectedWhitespace => "Unexpected whitespace in a MAC address string",
         };
-        f.write_str(s)
+        write!(
+            f,
+            "{} at byte {} (component {}, expected {} octets)",
+            s, self.offset, self.component, self.expected_len
+        )
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for ParseMacAddrError {}
+
+/// Error returned when constructing or parsing a [`MacNet`](crate::MacNet).
+#[derive(Copy, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum MacNetError {
+    /// The address part could not be parsed.
+    InvalidAddress(ParseMacAddrError),
+    /// The prefix length exceeded the address width or was not a number.
+    InvalidPrefixLength,
+    /// The mask had host bits set between network bits.
+    NonContiguousMask,
+    /// The input had no `/` prefix part.
+    MissingPrefix,
+}
+
+impl fmt::Display for MacNetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MacNetError::InvalidAddress(e) => write!(f, "Invalid address in a MAC prefix: {}", e),
+            MacNetError::InvalidPrefixLength => {
+                f.write_str("Invalid prefix length in a MAC prefix")
+            }
+            MacNetError::NonContiguousMask => f.write_str("Non-contiguous mask in a MAC prefix"),
+            MacNetError::MissingPrefix => f.write_str("Missing prefix length in a MAC prefix"),
+        }
+    }
+}
+
+impl From<ParseMacAddrError> for MacNetError {
+    #[inline]
+    fn from(e: ParseMacAddrError) -> Self {
+        MacNetError::InvalidAddress(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MacNetError {}
+
+/// Error returned when building or parsing a Wake-on-LAN [`MagicPacket`](crate::wol::MagicPacket).
+#[derive(Copy, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum WolError {
+    /// The packet was not 102, 106 or 108 bytes long.
+    InvalidLength,
+    /// The packet did not start with six `0xff` bytes.
+    MissingSync,
+    /// The 16 repetitions of the target address were not identical.
+    MismatchedRepetition,
+    /// A SecureOn password was not 4 or 6 bytes long.
+    InvalidPasswordLength,
+}
+
+impl fmt::Display for WolError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            WolError::InvalidLength => "Invalid magic packet length",
+            WolError::MissingSync => "Missing synchronization stream in a magic packet",
+            WolError::MismatchedRepetition => "Mismatched address repetition in a magic packet",
+            WolError::InvalidPasswordLength => "SecureOn password must be 4 or 6 bytes",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WolError {}
+
+/// Error returned when parsing or building an Ethernet header with [`ethernet`](crate::ethernet).
+#[derive(Copy, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum EthernetError {
+    /// The input ended inside the header.
+    Truncated,
+    /// The type/length field was between 1501 and 1535, which is neither a length nor an
+    /// EtherType, or a builder was given an EtherType that would not parse back as one.
+    InvalidTypeOrLength(u16),
+    /// An 802.3 length would exceed 1500 bytes.
+    LengthTooLarge,
+    /// The output buffer was too small for the header.
+    BufferTooSmall,
+    /// The LLC control field's format bits did not match its declared width.
+    InvalidLlcControl,
+    /// An LLC header announced SNAP but no SNAP header was given.
+    MissingSnap,
+    /// More than [`MAX_VLAN_TAGS`](crate::ethernet::MAX_VLAN_TAGS) VLAN tags were added.
+    TooManyVlanTags,
+}
+
+impl fmt::Display for EthernetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EthernetError::Truncated => f.write_str("Truncated Ethernet header"),
+            EthernetError::InvalidTypeOrLength(v) => {
+                write!(f, "Invalid Ethernet type/length field 0x{:04x}", v)
+            }
+            EthernetError::LengthTooLarge => f.write_str("IEEE 802.3 length exceeds 1500 bytes"),
+            EthernetError::BufferTooSmall => f.write_str("Buffer too small for Ethernet header"),
+            EthernetError::InvalidLlcControl => f.write_str("Invalid LLC control field format"),
+            EthernetError::MissingSnap => {
+                f.write_str("LLC header announces SNAP without a SNAP header")
+            }
+            EthernetError::TooManyVlanTags => f.write_str("Too many VLAN tags"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EthernetError {}
+
+/// Why a line of a registry file was rejected by [`OuiRegistry`](crate::OuiRegistry).
+#[cfg(feature = "std")]
+#[derive(Copy, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum MalformedLine {
+    /// A quoted CSV field was not terminated.
+    UnterminatedQuote,
+    /// The line had fewer fields than the format requires.
+    MissingField,
+    /// The IEEE registry name was not one of MA-L, MA-M, MA-S, CID or IAB.
+    UnknownRegistry,
+    /// The assignment or prefix was not valid hex.
+    InvalidPrefix,
+    /// The prefix length did not match the registry, or was not 24, 28 or 36 bits.
+    UnsupportedPrefixLength,
+}
+
+/// Error returned when loading a registry file into an [`OuiRegistry`](crate::OuiRegistry).
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum RegistryError {
+    /// Reading the input failed.
+    Io(std::io::Error),
+    /// A line could not be parsed. `line` is one-based.
+    Malformed { line: usize, kind: MalformedLine },
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegistryError::Io(e) => write!(f, "Failed to read registry data: {}", e),
+            RegistryError::Malformed { line, kind } => {
+                let s = match kind {
+                    MalformedLine::UnterminatedQuote => "unterminated quote",
+                    MalformedLine::MissingField => "missing field",
+                    MalformedLine::UnknownRegistry => "unknown registry",
+                    MalformedLine::InvalidPrefix => "invalid prefix",
+                    MalformedLine::UnsupportedPrefixLength => "unsupported prefix length",
+                };
+                write!(f, "Malformed registry line {}: {}", line, s)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for RegistryError {
+    #[inline]
+    fn from(e: std::io::Error) -> Self {
+        RegistryError::Io(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RegistryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RegistryError::Io(e) => Some(e),
+            RegistryError::Malformed { .. } => None,
+        }
+    }
+}
+
+/// Why a line of an ethers(5) file was rejected.
+#[cfg(feature = "std")]
+#[derive(Copy, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum MalformedEthersLine {
+    /// The first field was not a MAC address.
+    InvalidAddress(ParseMacAddrError),
+    /// The address was not followed by a hostname or IP address.
+    MissingHost,
+}
+
+/// Error returned when reading an ethers(5) file.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum EthersError {
+    /// Reading the input failed.
+    Io(std::io::Error),
+    /// A line could not be parsed. `line` is one-based.
+    Malformed {
+        line: usize,
+        kind: MalformedEthersLine,
+    },
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for EthersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EthersError::Io(e) => write!(f, "Failed to read ethers data: {}", e),
+            EthersError::Malformed { line, kind } => match kind {
+                MalformedEthersLine::InvalidAddress(e) => {
+                    write!(f, "Malformed ethers line {}: {}", line, e)
+                }
+                MalformedEthersLine::MissingHost => {
+                    write!(f, "Malformed ethers line {}: missing hostname", line)
+                }
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for EthersError {
+    #[inline]
+    fn from(e: std::io::Error) -> Self {
+        EthersError::Io(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EthersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EthersError::Io(e) => Some(e),
+            EthersError::Malformed {
+                kind: MalformedEthersLine::InvalidAddress(e),
+                ..
+            } => Some(e),
+            EthersError::Malformed { .. } => None,
+        }
+    }
+}
+
+/// Why a DHCP lease entry was rejected by the parsers in [`leases`](crate::leases).
+#[cfg(feature = "std")]
+#[derive(Copy, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum MalformedLease {
+    /// A required field or statement was missing.
+    MissingField,
+    /// The hardware address was not a MAC address.
+    InvalidAddress,
+    /// The leased IP address could not be parsed.
+    InvalidIp,
+    /// A timestamp could not be parsed.
+    InvalidTime,
+    /// A client ID or other hex field was malformed.
+    InvalidHex,
+    /// The lease has no hardware address, as with DHCPv6 leases identified by DUID.
+    NoHardwareAddress,
+    /// The client is not on Ethernet; holds the DHCP `htype` (6 token ring, 8 FDDI, 32 InfiniBand).
+    NonEthernet(u8),
+}
+
+/// Error returned when reading a DHCP lease file.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum LeaseError {
+    /// Reading the input failed.
+    Io(std::io::Error),
+    /// An entry could not be parsed. `line` is the one-based line where it starts.
+    Malformed { line: usize, kind: MalformedLease },
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for LeaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LeaseError::Io(e) => write!(f, "Failed to read lease data: {}", e),
+            LeaseError::Malformed { line, kind } => {
+                let s = match kind {
+                    MalformedLease::MissingField => "missing field",
+                    MalformedLease::InvalidAddress => "invalid hardware address",
+                    MalformedLease::InvalidIp => "invalid IP address",
+                    MalformedLease::InvalidTime => "invalid time",
+                    MalformedLease::InvalidHex => "invalid hex field",
+                    MalformedLease::NoHardwareAddress => "no hardware address",
+                    MalformedLease::NonEthernet(htype) => {
+                        return write!(
+                            f,
+                            "Malformed lease at line {}: non-Ethernet hardware type {}",
+                            line, htype
+                        );
+                    }
+                };
+                write!(f, "Malformed lease at line {}: {}", line, s)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for LeaseError {
+    #[inline]
+    fn from(e: std::io::Error) -> Self {
+        LeaseError::Io(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LeaseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LeaseError::Io(e) => Some(e),
+            LeaseError::Malformed { .. } => None,
+        }
+    }
+}
+
+/// Error returned by [`parse_proc_net_arp`](crate::sys::parse_proc_net_arp) for a line it cannot parse.
+#[cfg(all(feature = "sys", target_os = "linux"))]
+#[derive(Copy, Debug, PartialEq, Eq, Clone, Hash)]
+pub struct ArpParseError {
+    line: usize,
+}
+
+#[cfg(all(feature = "sys", target_os = "linux"))]
+impl ArpParseError {
+    #[inline]
+    pub(crate) const fn new(line: usize) -> ArpParseError {
+        ArpParseError { line }
+    }
+
+    /// Returns the one-based line number.
+    #[inline]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+#[cfg(all(feature = "sys", target_os = "linux"))]
+impl fmt::Display for ArpParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Malformed /proc/net/arp line {}", self.line)
+    }
+}
+
+#[cfg(all(feature = "sys", target_os = "linux"))]
+impl std::error::Error for ArpParseError {}
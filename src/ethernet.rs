@@ -0,0 +1,581 @@
+//! Ethernet II and IEEE 802.3 frame headers.
+//!
+//! [`EthernetHeader`] is a zero-copy view over a received frame and
+//! [`EthernetBuilder`] writes a header into a caller-provided buffer. Both
+//! handle 802.1Q and 802.1ad VLAN tags (including QinQ stacks) and 802.2
+//! LLC with an optional SNAP extension.
+
+use crate::addr::MacAddr;
+use crate::error::EthernetError;
+
+/// IPv4.
+pub const ETHERTYPE_IPV4: u16 = 0x0800;
+/// ARP.
+pub const ETHERTYPE_ARP: u16 = 0x0806;
+/// Wake-on-LAN.
+pub const ETHERTYPE_WOL: u16 = 0x0842;
+/// IEEE 802.1Q customer VLAN tag (C-tag).
+pub const ETHERTYPE_VLAN: u16 = 0x8100;
+/// IPv6.
+pub const ETHERTYPE_IPV6: u16 = 0x86dd;
+/// IEEE 802.1ad service VLAN tag (S-tag).
+pub const ETHERTYPE_QINQ: u16 = 0x88a8;
+/// LLDP.
+pub const ETHERTYPE_LLDP: u16 = 0x88cc;
+/// Pre-standard QinQ outer tag still used by some equipment.
+pub const ETHERTYPE_QINQ_LEGACY: u16 = 0x9100;
+
+/// Length of destination, source and type/length fields.
+const BASE_LEN: usize = 14;
+/// Largest value of the type/length field that is a length.
+const MAX_LENGTH: u16 = 1500;
+/// Smallest value of the type/length field that is an EtherType.
+const MIN_ETHERTYPE: u16 = 0x0600;
+/// Most VLAN tags [`EthernetBuilder`] can hold.
+pub const MAX_VLAN_TAGS: usize = 4;
+
+#[inline]
+const fn is_vlan_tpid(v: u16) -> bool {
+    matches!(v, ETHERTYPE_VLAN | ETHERTYPE_QINQ | ETHERTYPE_QINQ_LEGACY)
+}
+
+#[inline]
+fn u16_at(b: &[u8], at: usize) -> u16 {
+    u16::from_be_bytes([b[at], b[at + 1]])
+}
+
+/// IEEE 802.1Q/802.1ad VLAN tag.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct VlanTag {
+    /// Tag protocol identifier, such as [`ETHERTYPE_VLAN`] or [`ETHERTYPE_QINQ`].
+    pub tpid: u16,
+    /// Priority code point (3 bits).
+    pub pcp: u8,
+    /// Drop eligible indicator.
+    pub dei: bool,
+    /// VLAN identifier (12 bits).
+    pub vid: u16,
+}
+
+impl VlanTag {
+    /// Returns an 802.1Q customer tag (C-tag) for `vid` with priority 0.
+    #[inline]
+    pub const fn customer(vid: u16) -> VlanTag {
+        VlanTag {
+            tpid: ETHERTYPE_VLAN,
+            pcp: 0,
+            dei: false,
+            vid: vid & 0x0fff,
+        }
+    }
+
+    /// Returns an 802.1ad service tag (S-tag) for `vid` with priority 0.
+    #[inline]
+    pub const fn service(vid: u16) -> VlanTag {
+        VlanTag {
+            tpid: ETHERTYPE_QINQ,
+            pcp: 0,
+            dei: false,
+            vid: vid & 0x0fff,
+        }
+    }
+
+    /// Decodes a tag from its TPID and tag control information.
+    #[inline]
+    pub const fn from_tci(tpid: u16, tci: u16) -> VlanTag {
+        VlanTag {
+            tpid,
+            pcp: (tci >> 13) as u8,
+            dei: tci & 0x1000 != 0,
+            vid: tci & 0x0fff,
+        }
+    }
+
+    /// Returns the tag control information field.
+    #[inline]
+    pub const fn tci(&self) -> u16 {
+        ((self.pcp as u16 & 0x07) << 13) | ((self.dei as u16) << 12) | (self.vid & 0x0fff)
+    }
+}
+
+/// Meaning of the type/length field after any VLAN tags.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum TypeOrLength {
+    /// Ethernet II: the EtherType of the payload.
+    EtherType(u16),
+    /// IEEE 802.3: the length of the LLC header and data.
+    Length(u16),
+}
+
+/// IEEE 802.2 LLC header.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct Llc {
+    /// Destination service access point.
+    pub dsap: u8,
+    /// Source service access point.
+    pub ssap: u8,
+    /// Control field.
+    pub control: LlcControl,
+}
+
+/// IEEE 802.2 LLC control field.
+///
+/// The low two bits of the first byte select the format: `0b11` is a
+/// one-byte U-format field, anything else a two-byte I- or S-format field.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum LlcControl {
+    /// One-byte unnumbered (U-format) control field.
+    Unnumbered(u8),
+    /// Two-byte information or supervisory (I- or S-format) control field,
+    /// first byte in the high bits.
+    Sequenced(u16),
+}
+
+impl LlcControl {
+    /// Returns the encoded length: 1 or 2 bytes.
+    #[inline]
+    pub const fn encoded_len(&self) -> usize {
+        match self {
+            LlcControl::Unnumbered(_) => 1,
+            LlcControl::Sequenced(_) => 2,
+        }
+    }
+
+    /// Returns `true` if the format bits of the first byte agree with the variant.
+    #[inline]
+    pub const fn is_valid(&self) -> bool {
+        match *self {
+            LlcControl::Unnumbered(c) => c & 0x03 == 0x03,
+            LlcControl::Sequenced(c) => (c >> 8) & 0x03 != 0x03,
+        }
+    }
+}
+
+impl Llc {
+    /// LLC header that introduces a SNAP header (`aa aa 03`).
+    pub const SNAP: Llc = Llc {
+        dsap: 0xaa,
+        ssap: 0xaa,
+        control: LlcControl::Unnumbered(0x03),
+    };
+
+    /// Returns the encoded length: 3 or 4 bytes.
+    #[inline]
+    pub const fn encoded_len(&self) -> usize {
+        2 + self.control.encoded_len()
+    }
+
+    /// Returns `true` if this header is followed by SNAP.
+    #[inline]
+    pub const fn is_snap(&self) -> bool {
+        self.dsap == 0xaa
+            && self.ssap == 0xaa
+            && matches!(self.control, LlcControl::Unnumbered(0x03))
+    }
+}
+
+/// SNAP extension of an LLC header.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct Snap {
+    /// Organization code; `00:00:00` means `protocol_id` is an EtherType.
+    pub oui: [u8; 3],
+    /// Protocol identifier.
+    pub protocol_id: u16,
+}
+
+/// Zero-copy view of an Ethernet header.
+///
+/// ```
+/// use mac_addr::ethernet::{self, EthernetHeader, TypeOrLength};
+///
+/// let frame = [
+///     0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // destination
+///     0x00, 0x25, 0x96, 0x12, 0x34, 0x56, // source
+///     0x81, 0x00, 0x00, 0x64,             // 802.1Q, VLAN 100
+///     0x08, 0x06,                         // ARP
+///     0x00, 0x01,                         // payload...
+/// ];
+/// let header = EthernetHeader::parse(&frame).unwrap();
+/// assert!(header.destination().is_broadcast());
+/// assert_eq!(header.vlan_tags().next().unwrap().vid, 100);
+/// assert_eq!(header.type_or_length(), TypeOrLength::EtherType(ethernet::ETHERTYPE_ARP));
+/// assert_eq!(header.payload(), &[0x00, 0x01]);
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct EthernetHeader<'a> {
+    frame: &'a [u8],
+    /// Offset of the type/length field after any VLAN tags.
+    type_offset: usize,
+    header_len: usize,
+    payload_end: usize,
+}
+
+impl<'a> EthernetHeader<'a> {
+    /// Parses the header at the start of `frame`.
+    pub fn parse(frame: &'a [u8]) -> Result<EthernetHeader<'a>, EthernetError> {
+        if frame.len() < BASE_LEN {
+            return Err(EthernetError::Truncated);
+        }
+        let mut type_offset = 12;
+        while is_vlan_tpid(u16_at(frame, type_offset)) {
+            type_offset += 4;
+            if frame.len() < type_offset + 2 {
+                return Err(EthernetError::Truncated);
+            }
+        }
+        let raw = u16_at(frame, type_offset);
+        if raw > MAX_LENGTH && raw < MIN_ETHERTYPE {
+            return Err(EthernetError::InvalidTypeOrLength(raw));
+        }
+        let mut header = EthernetHeader {
+            frame,
+            type_offset,
+            header_len: type_offset + 2,
+            payload_end: frame.len(),
+        };
+        match header.type_or_length() {
+            TypeOrLength::EtherType(_) => {}
+            TypeOrLength::Length(length) => {
+                let llc_start = header.header_len;
+                let llc = header.llc().ok_or(EthernetError::Truncated)?;
+                header.header_len += llc.encoded_len();
+                if llc.is_snap() {
+                    header.header_len += 5;
+                }
+                if header.header_len > frame.len() {
+                    return Err(EthernetError::Truncated);
+                }
+                // Anything past the 802.3 length is padding.
+                header.payload_end =
+                    (llc_start + length as usize).clamp(header.header_len, frame.len());
+            }
+        }
+        Ok(header)
+    }
+
+    /// Returns the destination address.
+    #[inline]
+    pub fn destination(&self) -> MacAddr {
+        let f = self.frame;
+        MacAddr::new(f[0], f[1], f[2], f[3], f[4], f[5])
+    }
+
+    /// Returns the source address.
+    #[inline]
+    pub fn source(&self) -> MacAddr {
+        let f = self.frame;
+        MacAddr::new(f[6], f[7], f[8], f[9], f[10], f[11])
+    }
+
+    /// Returns the VLAN tags from outermost to innermost.
+    #[inline]
+    pub fn vlan_tags(&self) -> VlanTags<'a> {
+        VlanTags {
+            tags: &self.frame[12..self.type_offset],
+        }
+    }
+
+    /// Returns the number of VLAN tags.
+    #[inline]
+    pub fn vlan_count(&self) -> usize {
+        (self.type_offset - 12) / 4
+    }
+
+    /// Returns the type/length field after any VLAN tags.
+    #[inline]
+    pub fn type_or_length(&self) -> TypeOrLength {
+        let v = u16_at(self.frame, self.type_offset);
+        if v <= MAX_LENGTH {
+            TypeOrLength::Length(v)
+        } else {
+            TypeOrLength::EtherType(v)
+        }
+    }
+
+    /// Returns the EtherType of the payload.
+    ///
+    /// For 802.3 frames this is the SNAP protocol ID when the SNAP OUI is
+    /// `00:00:00` (RFC 1042 encapsulation), and `None` otherwise.
+    pub fn ether_type(&self) -> Option<u16> {
+        match self.type_or_length() {
+            TypeOrLength::EtherType(t) => Some(t),
+            TypeOrLength::Length(_) => match self.snap() {
+                Some(Snap {
+                    oui: [0, 0, 0],
+                    protocol_id,
+                }) => Some(protocol_id),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns the LLC header of an 802.3 frame.
+    pub fn llc(&self) -> Option<Llc> {
+        if !matches!(self.type_or_length(), TypeOrLength::Length(_)) {
+            return None;
+        }
+        let at = self.type_offset + 2;
+        let b = self.frame.get(at..at + 3)?;
+        let control = if b[2] & 0x03 == 0x03 {
+            LlcControl::Unnumbered(b[2])
+        } else {
+            LlcControl::Sequenced(u16::from_be_bytes([b[2], *self.frame.get(at + 3)?]))
+        };
+        Some(Llc {
+            dsap: b[0],
+            ssap: b[1],
+            control,
+        })
+    }
+
+    /// Returns the SNAP header of an 802.3 frame whose LLC header is `aa aa 03`.
+    pub fn snap(&self) -> Option<Snap> {
+        if !self.llc()?.is_snap() {
+            return None;
+        }
+        let at = self.type_offset + 5;
+        let b = self.frame.get(at..at + 5)?;
+        Some(Snap {
+            oui: [b[0], b[1], b[2]],
+            protocol_id: u16::from_be_bytes([b[3], b[4]]),
+        })
+    }
+
+    /// Returns the header length, including VLAN tags and any LLC/SNAP header.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.header_len
+    }
+
+    /// Returns the header bytes.
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        &self.frame[..self.header_len]
+    }
+
+    /// Returns the bytes after the header. For 802.3 frames, padding beyond the length is excluded.
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        &self.frame[self.header_len..self.payload_end]
+    }
+}
+
+/// Iterator returned by [`EthernetHeader::vlan_tags`].
+#[derive(Clone, Debug)]
+pub struct VlanTags<'a> {
+    tags: &'a [u8],
+}
+
+impl Iterator for VlanTags<'_> {
+    type Item = VlanTag;
+
+    fn next(&mut self) -> Option<VlanTag> {
+        if self.tags.len() < 4 {
+            return None;
+        }
+        let tag = VlanTag::from_tci(u16_at(self.tags, 0), u16_at(self.tags, 2));
+        self.tags = &self.tags[4..];
+        Some(tag)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.tags.len() / 4;
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for VlanTags<'_> {}
+
+impl core::iter::FusedIterator for VlanTags<'_> {}
+
+/// What follows the addresses and VLAN tags in a built header.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+enum Body {
+    EtherType(u16),
+    Llc {
+        llc: Llc,
+        snap: Option<Snap>,
+        data_len: usize,
+    },
+}
+
+/// Writes Ethernet headers into a caller-provided buffer.
+///
+/// ```
+/// use mac_addr::MacAddr;
+/// use mac_addr::ethernet::{self, EthernetBuilder, EthernetHeader, VlanTag};
+///
+/// let dst = MacAddr::broadcast();
+/// let src = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+/// let builder = EthernetBuilder::new(dst, src, ethernet::ETHERTYPE_IPV4)
+///     .vlan(VlanTag::service(10))
+///     .vlan(VlanTag::customer(20));
+/// let mut buf = [0u8; 64];
+/// let len = builder.write(&mut buf).unwrap();
+/// assert_eq!(len, 22);
+///
+/// let header = EthernetHeader::parse(&buf).unwrap();
+/// assert_eq!(header.source(), src);
+/// assert_eq!(header.vlan_tags().map(|t| t.vid).collect::<Vec<_>>(), [10, 20]);
+/// assert_eq!(header.ether_type(), Some(ethernet::ETHERTYPE_IPV4));
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct EthernetBuilder {
+    destination: MacAddr,
+    source: MacAddr,
+    tags: [VlanTag; MAX_VLAN_TAGS],
+    tag_count: usize,
+    body: Body,
+}
+
+impl EthernetBuilder {
+    /// Starts an Ethernet II header carrying `ether_type`.
+    ///
+    /// [`EthernetBuilder::write`] fails if `ether_type` is below `0x0600` or
+    /// is a VLAN TPID, since the header would not parse back as written; use
+    /// [`EthernetBuilder::ieee8023`] for length-based frames and
+    /// [`EthernetBuilder::vlan`] for tags.
+    #[inline]
+    pub const fn new(destination: MacAddr, source: MacAddr, ether_type: u16) -> EthernetBuilder {
+        EthernetBuilder {
+            destination,
+            source,
+            tags: [VlanTag::customer(0); MAX_VLAN_TAGS],
+            tag_count: 0,
+            body: Body::EtherType(ether_type),
+        }
+    }
+
+    /// Starts an IEEE 802.3 header.
+    ///
+    /// The header carries a null-SAP UI LLC header and no data until
+    /// [`EthernetBuilder::llc`] or [`EthernetBuilder::snap`] replaces it.
+    #[inline]
+    pub const fn ieee8023(destination: MacAddr, source: MacAddr) -> EthernetBuilder {
+        EthernetBuilder {
+            destination,
+            source,
+            tags: [VlanTag::customer(0); MAX_VLAN_TAGS],
+            tag_count: 0,
+            body: Body::Llc {
+                llc: Llc {
+                    dsap: 0,
+                    ssap: 0,
+                    control: LlcControl::Unnumbered(0x03),
+                },
+                snap: None,
+                data_len: 0,
+            },
+        }
+    }
+
+    /// Appends a VLAN tag. The first tag added is the outermost.
+    ///
+    /// At most [`MAX_VLAN_TAGS`] tags fit; [`EthernetBuilder::write`] fails
+    /// if more are added.
+    #[inline]
+    pub fn vlan(mut self, tag: VlanTag) -> EthernetBuilder {
+        if let Some(slot) = self.tags.get_mut(self.tag_count) {
+            *slot = tag;
+        }
+        self.tag_count = self.tag_count.saturating_add(1);
+        self
+    }
+
+    /// Makes this an 802.3 header with `llc`, followed by `data_len` bytes of data.
+    ///
+    /// [`EthernetBuilder::write`] fails if the control field's format bits do
+    /// not match its [`LlcControl`] variant, or if `llc` is [`Llc::SNAP`];
+    /// use [`EthernetBuilder::snap`] for SNAP headers.
+    #[inline]
+    pub fn llc(mut self, llc: Llc, data_len: usize) -> EthernetBuilder {
+        self.body = Body::Llc {
+            llc,
+            snap: None,
+            data_len,
+        };
+        self
+    }
+
+    /// Makes this an 802.3 header with LLC/SNAP, followed by `data_len` bytes of data.
+    #[inline]
+    pub fn snap(mut self, snap: Snap, data_len: usize) -> EthernetBuilder {
+        self.body = Body::Llc {
+            llc: Llc::SNAP,
+            snap: Some(snap),
+            data_len,
+        };
+        self
+    }
+
+    /// Returns the number of bytes [`EthernetBuilder::write`] produces.
+    pub fn header_len(&self) -> usize {
+        let base = BASE_LEN + self.tag_count.min(MAX_VLAN_TAGS) * 4;
+        match self.body {
+            Body::EtherType(_) => base,
+            Body::Llc { llc, snap, .. } => {
+                base + llc.encoded_len() + if snap.is_some() { 5 } else { 0 }
+            }
+        }
+    }
+
+    /// Writes the header to the start of `buf` and returns its length.
+    pub fn write(&self, buf: &mut [u8]) -> Result<usize, EthernetError> {
+        if self.tag_count > MAX_VLAN_TAGS {
+            return Err(EthernetError::TooManyVlanTags);
+        }
+        if let Body::EtherType(t) = self.body
+            && (t < MIN_ETHERTYPE || is_vlan_tpid(t))
+        {
+            return Err(EthernetError::InvalidTypeOrLength(t));
+        }
+        let len = self.header_len();
+        let buf = buf.get_mut(..len).ok_or(EthernetError::BufferTooSmall)?;
+        buf[0..6].copy_from_slice(&self.destination.octets());
+        buf[6..12].copy_from_slice(&self.source.octets());
+        let mut at = 12;
+        for tag in &self.tags[..self.tag_count] {
+            buf[at..at + 2].copy_from_slice(&tag.tpid.to_be_bytes());
+            buf[at + 2..at + 4].copy_from_slice(&tag.tci().to_be_bytes());
+            at += 4;
+        }
+        match self.body {
+            Body::EtherType(t) => buf[at..at + 2].copy_from_slice(&t.to_be_bytes()),
+            Body::Llc {
+                llc,
+                snap,
+                data_len,
+            } => {
+                if !llc.control.is_valid() {
+                    return Err(EthernetError::InvalidLlcControl);
+                }
+                if llc.is_snap() && snap.is_none() {
+                    return Err(EthernetError::MissingSnap);
+                }
+                let length = (len - at - 2)
+                    .checked_add(data_len)
+                    .filter(|&l| l <= MAX_LENGTH as usize)
+                    .ok_or(EthernetError::LengthTooLarge)?;
+                buf[at..at + 2].copy_from_slice(&(length as u16).to_be_bytes());
+                buf[at + 2] = llc.dsap;
+                buf[at + 3] = llc.ssap;
+                let mut next = at + 4;
+                match llc.control {
+                    LlcControl::Unnumbered(c) => {
+                        buf[next] = c;
+                        next += 1;
+                    }
+                    LlcControl::Sequenced(c) => {
+                        buf[next..next + 2].copy_from_slice(&c.to_be_bytes());
+                        next += 2;
+                    }
+                }
+                if let Some(snap) = snap {
+                    buf[next..next + 3].copy_from_slice(&snap.oui);
+                    buf[next + 3..next + 5].copy_from_slice(&snap.protocol_id.to_be_bytes());
+                }
+            }
+        }
+        Ok(len)
+    }
+}
@@ -0,0 +1,275 @@
+//! Reading and writing ethers(5) files such as `/etc/ethers`.
+//!
+//! Each line maps a MAC address to a hostname or IP address:
+//!
+//! ```text
+//! # comment
+//! 8:0:20:1:2:3        sparc1
+//! 00:25:96:12:34:56   192.0.2.10   # trailing comment
+//! ```
+//!
+//! Addresses are parsed with [`ParseMode::Standard`], so octets may be
+//! written with one or two hex digits. Fields after the hostname are ignored,
+//! as in the C library.
+
+use crate::addr::MacAddr;
+use crate::error::{EthersError, MalformedEthersLine};
+use crate::parse::ParseMode;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, BufRead, Lines};
+use std::net::IpAddr;
+
+/// Right-hand side of an ethers entry.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Host {
+    /// A hostname.
+    Name(String),
+    /// An IPv4 or IPv6 address.
+    Ip(IpAddr),
+}
+
+impl Host {
+    /// Parses an IP address, or takes `s` as a hostname otherwise.
+    pub fn parse(s: &str) -> Host {
+        match s.parse() {
+            Ok(ip) => Host::Ip(ip),
+            Err(_) => Host::Name(s.to_string()),
+        }
+    }
+
+    /// Returns `true` if the host can be written to an ethers file and read back unchanged.
+    ///
+    /// Names must be non-empty, must not contain whitespace or `#`, and must
+    /// not themselves parse as an IP address.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Host::Name(name) => {
+                !name.is_empty()
+                    && !name.contains(|c: char| c.is_whitespace() || c == '#')
+                    && name.parse::<IpAddr>().is_err()
+            }
+            Host::Ip(_) => true,
+        }
+    }
+}
+
+impl fmt::Display for Host {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Host::Name(name) => f.write_str(name),
+            Host::Ip(ip) => write!(f, "{}", ip),
+        }
+    }
+}
+
+/// One mapping from an ethers file.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Entry {
+    /// The hardware address.
+    pub mac: MacAddr,
+    /// The hostname or IP address it maps to.
+    pub host: Host,
+}
+
+impl fmt::Display for Entry {
+    /// Writes the entry as an ethers line, without the newline.
+    ///
+    /// The host is not checked; see [`Host::is_valid`].
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.mac, self.host)
+    }
+}
+
+/// Parses a single line. Returns `Ok(None)` for blank and comment-only lines.
+pub fn parse_line(line: &str) -> Result<Option<Entry>, MalformedEthersLine> {
+    let data = match line.split_once('#') {
+        Some((data, _)) => data,
+        None => line,
+    };
+    let mut fields = data.split_whitespace();
+    let Some(mac) = fields.next() else {
+        return Ok(None);
+    };
+    let mac = MacAddr::parse_with_mode(mac, ParseMode::Standard)
+        .map_err(MalformedEthersLine::InvalidAddress)?;
+    let host = fields.next().ok_or(MalformedEthersLine::MissingHost)?;
+    Ok(Some(Entry {
+        mac,
+        host: Host::parse(host),
+    }))
+}
+
+/// Returns a streaming parser over the lines of `reader`.
+///
+/// ```
+/// use mac_addr::ethers::{self, Host};
+///
+/// let text = "# lab hosts\n8:0:20:1:2:3 sparc1\n";
+/// let entries: Vec<_> = ethers::parse(text.as_bytes()).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(entries[0].mac, "08:00:20:01:02:03".parse().unwrap());
+/// assert_eq!(entries[0].host, Host::Name("sparc1".into()));
+/// ```
+pub fn parse<R: BufRead>(reader: R) -> Entries<R> {
+    Entries {
+        lines: reader.lines(),
+        line: 0,
+    }
+}
+
+/// Iterator returned by [`parse`].
+#[derive(Debug)]
+pub struct Entries<R> {
+    lines: Lines<R>,
+    line: usize,
+}
+
+impl<R: BufRead> Iterator for Entries<R> {
+    type Item = Result<Entry, EthersError>;
+
+    fn next(&mut self) -> Option<Result<Entry, EthersError>> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            self.line += 1;
+            match parse_line(&line) {
+                Ok(Some(entry)) => return Some(Ok(entry)),
+                Ok(None) => continue,
+                Err(kind) => {
+                    return Some(Err(EthersError::Malformed {
+                        line: self.line,
+                        kind,
+                    }));
+                }
+            }
+        }
+    }
+}
+
+/// Writes `entries` as ethers lines.
+///
+/// Fails with [`io::ErrorKind::InvalidInput`] if a host would not read back
+/// as written (see [`Host::is_valid`]); entries before it have already been
+/// written.
+pub fn write<'a, W, I>(mut w: W, entries: I) -> io::Result<()>
+where
+    W: io::Write,
+    I: IntoIterator<Item = &'a Entry>,
+{
+    for entry in entries {
+        if !entry.host.is_valid() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid ethers hostname {:?}", entry.host.to_string()),
+            ));
+        }
+        writeln!(w, "{}", entry)?;
+    }
+    Ok(())
+}
+
+/// In-memory ethers table with lookup in both directions.
+///
+/// Entries keep their file order. As with `ether_ntohost(3)` and
+/// `ether_hostton(3)`, the first entry wins when an address or host appears
+/// more than once.
+///
+/// ```
+/// use mac_addr::ethers::{EthersMap, Host};
+/// use mac_addr::MacAddr;
+///
+/// let map = EthersMap::from_reader("0:25:96:12:34:56 build01\n".as_bytes()).unwrap();
+/// let mac: MacAddr = "00:25:96:12:34:56".parse().unwrap();
+/// assert_eq!(map.host(mac), Some(&Host::Name("build01".into())));
+/// assert_eq!(map.mac_addr("build01"), Some(mac));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct EthersMap {
+    entries: Vec<Entry>,
+    by_mac: HashMap<MacAddr, usize>,
+    by_host: HashMap<String, usize>,
+}
+
+impl EthersMap {
+    /// Creates an empty map.
+    #[inline]
+    pub fn new() -> EthersMap {
+        EthersMap::default()
+    }
+
+    /// Reads every entry of an ethers file, failing on the first malformed line.
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<EthersMap, EthersError> {
+        let mut map = EthersMap::new();
+        for entry in parse(reader) {
+            map.insert(entry?);
+        }
+        Ok(map)
+    }
+
+    /// Appends an entry.
+    pub fn insert(&mut self, entry: Entry) {
+        let i = self.entries.len();
+        self.by_mac.entry(entry.mac).or_insert(i);
+        self.by_host.entry(entry.host.to_string()).or_insert(i);
+        self.entries.push(entry);
+    }
+
+    /// Returns the number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map holds no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the host mapped to `mac`.
+    pub fn host(&self, mac: MacAddr) -> Option<&Host> {
+        self.by_mac.get(&mac).map(|&i| &self.entries[i].host)
+    }
+
+    /// Returns the address mapped to a hostname or the textual form of an IP address.
+    pub fn mac_addr(&self, host: &str) -> Option<MacAddr> {
+        let key = match host.parse::<IpAddr>() {
+            // Normalize so that e.g. `::01` finds `::1`.
+            Ok(ip) => ip.to_string(),
+            Err(_) => host.to_string(),
+        };
+        self.by_host.get(&key).map(|&i| self.entries[i].mac)
+    }
+
+    /// Returns the entries in insertion order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Entry> {
+        self.entries.iter()
+    }
+
+    /// Writes every entry as an ethers line.
+    #[inline]
+    pub fn write_to<W: io::Write>(&self, w: W) -> io::Result<()> {
+        write(w, &self.entries)
+    }
+}
+
+impl<'a> IntoIterator for &'a EthersMap {
+    type Item = &'a Entry;
+    type IntoIter = std::slice::Iter<'a, Entry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+impl FromIterator<Entry> for EthersMap {
+    fn from_iter<T: IntoIterator<Item = Entry>>(iter: T) -> EthersMap {
+        let mut map = EthersMap::new();
+        for entry in iter {
+            map.insert(entry);
+        }
+        map
+    }
+}
@@ -0,0 +1,206 @@
+use crate::parse::MacNotation;
+use core::fmt;
+
+const HEX_LOWER: &[u8; 16] = b"0123456789abcdef";
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Separator placed between groups by [`MacFormat`].
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Separator {
+    /// `:`
+    Colon,
+    /// `-`
+    Hyphen,
+    /// `.`
+    Dot,
+    /// ` `
+    Space,
+    /// No separator.
+    None,
+}
+
+impl Separator {
+    /// Returns the separator character, or `None` for [`Separator::None`].
+    #[inline]
+    pub const fn as_char(&self) -> Option<char> {
+        match self {
+            Separator::Colon => Some(':'),
+            Separator::Hyphen => Some('-'),
+            Separator::Dot => Some('.'),
+            Separator::Space => Some(' '),
+            Separator::None => None,
+        }
+    }
+}
+
+/// Allocation-free, configurable MAC address formatter.
+///
+/// ```
+/// use mac_addr::{MacAddr, MacFormat, Separator};
+///
+/// let mac = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+/// let cisco = MacFormat::new().separator(Separator::Dot).group_size(2);
+/// let mut buf = [0u8; 32];
+/// assert_eq!(mac.format_into(&cisco, &mut buf).unwrap(), "0025.9612.3456");
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct MacFormat {
+    separator: Separator,
+    group_size: usize,
+    uppercase: bool,
+    prefix: &'static str,
+}
+
+impl MacFormat {
+    /// Lowercase, colon-separated octets without prefix (`00:25:96:12:34:56`).
+    #[inline]
+    pub const fn new() -> MacFormat {
+        MacFormat {
+            separator: Separator::Colon,
+            group_size: 1,
+            uppercase: false,
+            prefix: "",
+        }
+    }
+
+    /// Sets the separator placed between groups.
+    #[inline]
+    pub const fn separator(mut self, separator: Separator) -> MacFormat {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the number of octets per group (typically 1, 2 or 4). `0` is treated as `1`.
+    #[inline]
+    pub const fn group_size(mut self, octets: usize) -> MacFormat {
+        self.group_size = if octets == 0 { 1 } else { octets };
+        self
+    }
+
+    /// Selects uppercase (`true`) or lowercase (`false`) hex digits.
+    #[inline]
+    pub const fn uppercase(mut self, uppercase: bool) -> MacFormat {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Sets a string written before the address, such as `"0x"`.
+    #[inline]
+    pub const fn prefix(mut self, prefix: &'static str) -> MacFormat {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Returns the number of bytes produced when formatting `octets` octets.
+    #[inline]
+    pub const fn formatted_len(&self, octets: usize) -> usize {
+        if octets == 0 {
+            return self.prefix.len();
+        }
+        let separators = match self.separator {
+            Separator::None => 0,
+            _ => (octets - 1) / self.group_size,
+        };
+        self.prefix.len() + octets * 2 + separators
+    }
+
+    /// Writes `octets` to any [`fmt::Write`] sink.
+    #[inline]
+    pub fn write<W: fmt::Write + ?Sized>(&self, octets: &[u8], w: &mut W) -> fmt::Result {
+        self.write_redacted(octets, octets.len(), w)
+    }
+
+    /// Writes `octets`, replacing every octet from index `visible` on with `xx`.
+    pub(crate) fn write_redacted<W: fmt::Write + ?Sized>(
+        &self,
+        octets: &[u8],
+        visible: usize,
+        w: &mut W,
+    ) -> fmt::Result {
+        let digits = if self.uppercase { HEX_UPPER } else { HEX_LOWER };
+        let hidden = if self.uppercase { 'X' } else { 'x' };
+        w.write_str(self.prefix)?;
+        for (i, b) in octets.iter().enumerate() {
+            if i > 0
+                && i % self.group_size == 0
+                && let Some(c) = self.separator.as_char()
+            {
+                w.write_char(c)?;
+            }
+            if i >= visible {
+                w.write_char(hidden)?;
+                w.write_char(hidden)?;
+                continue;
+            }
+            w.write_char(digits[(b >> 4) as usize] as char)?;
+            w.write_char(digits[(b & 0x0f) as usize] as char)?;
+        }
+        Ok(())
+    }
+
+    /// Writes `octets` into `buf` and returns the written part as `&str`.
+    ///
+    /// Fails with [`fmt::Error`] if `buf` is too small.
+    pub fn write_to_buf<'a>(
+        &self,
+        octets: &[u8],
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, fmt::Error> {
+        let mut w = BufWriter { buf, len: 0 };
+        self.write(octets, &mut w)?;
+        let BufWriter { buf, len } = w;
+        core::str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)
+    }
+}
+
+impl Default for MacFormat {
+    #[inline]
+    fn default() -> MacFormat {
+        MacFormat::new()
+    }
+}
+
+impl From<MacNotation> for MacFormat {
+    /// Returns the format that reproduces the given notation in lowercase.
+    fn from(notation: MacNotation) -> MacFormat {
+        let base = MacFormat::new();
+        match notation {
+            MacNotation::Colon => base,
+            MacNotation::Hyphen => base.separator(Separator::Hyphen),
+            MacNotation::Space => base.separator(Separator::Space),
+            MacNotation::Dotted => base.separator(Separator::Dot).group_size(2),
+            MacNotation::Bare => base.separator(Separator::None),
+        }
+    }
+}
+
+/// [`fmt::Display`] adapter returned by the `display_with` methods of
+/// [`MacAddr`](crate::MacAddr), [`MacAddr8`](crate::MacAddr8) and [`HwAddr`](crate::HwAddr).
+#[derive(Clone, Copy, Debug)]
+pub struct FormattedMac<const N: usize> {
+    pub(crate) octets: [u8; N],
+    pub(crate) format: MacFormat,
+}
+
+impl<const N: usize> fmt::Display for FormattedMac<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format.write(&self.octets, f)
+    }
+}
+
+struct BufWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl fmt::Write for BufWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len.checked_add(s.len()).ok_or(fmt::Error)?;
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
@@ -0,0 +1,296 @@
+use crate::addr::MacAddr;
+use crate::addr8::MacAddr8;
+use crate::anonymize::Redacted;
+use crate::error::ParseMacAddrError;
+use crate::format::{FormattedMac, MacFormat};
+use crate::parse::{self, MacNotation, ParseMode};
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+/// Link-layer address of any length `N`.
+///
+/// Covers hardware types that are not 6 or 8 octets, such as 20-octet
+/// InfiniBand (IPoIB) or 2-octet IEEE 802.15.4 short addresses. Parsing,
+/// formatting, serde and the IEEE I/G and U/L bit checks behave as for
+/// [`MacAddr`] and [`MacAddr8`], which convert to and from `HwAddr<6>` and
+/// `HwAddr<8>`.
+///
+/// ```
+/// use mac_addr::HwAddr;
+///
+/// let short: HwAddr<2> = "ab:cd".parse().unwrap();
+/// assert_eq!(short.octets(), [0xab, 0xcd]);
+///
+/// let ipoib = "80:00:00:48:fe:80:00:00:00:00:00:00:00:02:c9:03:00:a1:b2:c3";
+/// let a: HwAddr<20> = ipoib.parse().unwrap();
+/// assert_eq!(a.to_string(), ipoib);
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct HwAddr<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> HwAddr<N> {
+    /// Number of octets in the address.
+    pub const LEN: usize = N;
+
+    /// Constructs from an array of octets.
+    #[inline]
+    pub const fn new(octets: [u8; N]) -> HwAddr<N> {
+        HwAddr(octets)
+    }
+
+    /// Returns the octets backing this address.
+    #[inline]
+    pub const fn octets(&self) -> [u8; N] {
+        self.0
+    }
+
+    /// Returns the octets as a slice.
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the all-zeros address.
+    #[inline]
+    pub const fn zero() -> HwAddr<N> {
+        HwAddr([0; N])
+    }
+
+    /// Returns the all-ones (broadcast) address.
+    #[inline]
+    pub const fn broadcast() -> HwAddr<N> {
+        HwAddr([0xff; N])
+    }
+
+    /// Parses any notation in [`MacNotation`] and reports which one was used.
+    ///
+    /// Dotted notation is only accepted when `N` is even.
+    #[inline]
+    pub const fn parse_with_notation(
+        s: &str,
+    ) -> Result<(HwAddr<N>, MacNotation), ParseMacAddrError> {
+        match parse::parse_octets::<N>(s, ParseMode::Standard) {
+            Ok((octets, notation)) => Ok((HwAddr(octets), notation)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Parses any notation accepted by [`HwAddr::parse_with_notation`] under the given [`ParseMode`].
+    #[inline]
+    pub const fn parse_with_mode(s: &str, mode: ParseMode) -> Result<HwAddr<N>, ParseMacAddrError> {
+        match parse::parse_octets::<N>(s, mode) {
+            Ok((octets, _)) => Ok(HwAddr(octets)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns a [`fmt::Display`] adapter that renders the address using `format`.
+    #[inline]
+    pub fn display_with(&self, format: MacFormat) -> FormattedMac<N> {
+        FormattedMac {
+            octets: self.0,
+            format,
+        }
+    }
+
+    /// Formats the address into `buf` without allocating.
+    ///
+    /// Fails with [`fmt::Error`] if `buf` is too small.
+    #[inline]
+    pub fn format_into<'a>(
+        &self,
+        format: &MacFormat,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, fmt::Error> {
+        format.write_to_buf(&self.0, buf)
+    }
+
+    /// Returns a [`fmt::Display`] adapter that shows the first `visible` octets and masks the rest.
+    #[inline]
+    pub fn redacted(&self, visible: usize) -> Redacted<N> {
+        Redacted::new(self.0, visible)
+    }
+
+    /// Returns `true` if every octet is `0xff`.
+    #[inline]
+    pub const fn is_broadcast(&self) -> bool {
+        let mut i = 0;
+        while i < N {
+            if self.0[i] != 0xff {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns `true` if every octet is zero.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        let mut i = 0;
+        while i < N {
+            if self.0[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns `true` if the I/G bit of the first octet is set.
+    #[inline]
+    pub const fn is_multicast(&self) -> bool {
+        N > 0 && self.0[0] & 0x01 == 0x01
+    }
+
+    /// Returns `true` if the address is neither multicast nor broadcast.
+    #[inline]
+    pub const fn is_unicast(&self) -> bool {
+        !self.is_multicast() && !self.is_broadcast()
+    }
+
+    /// Returns `true` if the U/L bit of the first octet is set.
+    #[inline]
+    pub const fn is_locally_administered(&self) -> bool {
+        N > 0 && self.0[0] & 0x02 == 0x02
+    }
+
+    /// Returns `true` if the address is universally administered.
+    #[inline]
+    pub const fn is_universal(&self) -> bool {
+        !self.is_locally_administered()
+    }
+}
+
+impl<const N: usize> Default for HwAddr<N> {
+    #[inline]
+    fn default() -> HwAddr<N> {
+        HwAddr::zero()
+    }
+}
+
+impl<const N: usize> fmt::Display for HwAddr<N> {
+    /// Lowercase hex with `:` separators.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        MacFormat::new().write(&self.0, f)
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for HwAddr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        MacFormat::new().write(&self.0, f)
+    }
+}
+
+impl<const N: usize> fmt::UpperHex for HwAddr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        MacFormat::new().uppercase(true).write(&self.0, f)
+    }
+}
+
+impl<const N: usize> FromStr for HwAddr<N> {
+    type Err = ParseMacAddrError;
+
+    /// Parses any notation accepted by [`HwAddr::parse_with_notation`].
+    fn from_str(s: &str) -> Result<HwAddr<N>, ParseMacAddrError> {
+        HwAddr::parse_with_notation(s).map(|(addr, _)| addr)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> Serialize for HwAddr<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> Deserialize<'de> for HwAddr<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct HwAddrVisitor<const N: usize>;
+        impl<'de, const N: usize> de::Visitor<'de> for HwAddrVisitor<N> {
+            type Value = HwAddr<N>;
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<HwAddr<N>, E> {
+                value.parse().map_err(E::custom)
+            }
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<HwAddr<N>, E> {
+                HwAddr::try_from(v).map_err(|_| E::invalid_length(v.len(), &self))
+            }
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "either a string MAC address or {}-byte array", N)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HwAddrVisitor::<N>)
+        } else {
+            deserializer.deserialize_bytes(HwAddrVisitor::<N>)
+        }
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for HwAddr<N> {
+    #[inline]
+    fn from(v: [u8; N]) -> Self {
+        HwAddr(v)
+    }
+}
+
+impl<const N: usize> From<HwAddr<N>> for [u8; N] {
+    #[inline]
+    fn from(a: HwAddr<N>) -> Self {
+        a.0
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for HwAddr<N> {
+    type Error = ();
+
+    #[inline]
+    fn try_from(s: &[u8]) -> Result<Self, Self::Error> {
+        s.try_into().map(HwAddr).map_err(|_| ())
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for HwAddr<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<MacAddr> for HwAddr<6> {
+    #[inline]
+    fn from(m: MacAddr) -> Self {
+        HwAddr(m.octets())
+    }
+}
+
+impl From<HwAddr<6>> for MacAddr {
+    #[inline]
+    fn from(a: HwAddr<6>) -> Self {
+        MacAddr::from_octets(a.0)
+    }
+}
+
+impl From<MacAddr8> for HwAddr<8> {
+    #[inline]
+    fn from(m: MacAddr8) -> Self {
+        HwAddr(m.octets())
+    }
+}
+
+impl From<HwAddr<8>> for MacAddr8 {
+    #[inline]
+    fn from(a: HwAddr<8>) -> Self {
+        MacAddr8::from_octets(a.0)
+    }
+}
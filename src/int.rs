@@ -0,0 +1,83 @@
+use crate::addr::MacAddr;
+use crate::addr8::MacAddr8;
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for crate::MacAddr {}
+    impl Sealed for crate::MacAddr8 {}
+}
+
+/// Address types that map onto an unsigned integer, first octet most significant.
+///
+/// Implemented by [`MacAddr`] (48 bits) and [`MacAddr8`] (64 bits). This trait
+/// is sealed; it exists so that [`MacRange`](crate::MacRange) and similar types
+/// can be generic over both address widths.
+pub trait MacInteger: Copy + Ord + sealed::Sealed {
+    /// Width of the address in bits.
+    const BITS: u32;
+    /// Largest integer value of the address.
+    const MAX: u64;
+
+    /// Returns the address as an integer.
+    fn to_u64(self) -> u64;
+
+    /// Constructs an address from the low [`Self::BITS`] bits of `v`.
+    fn from_u64(v: u64) -> Self;
+
+    /// Returns a mask with the first `prefix_len` bits set, or `None` if
+    /// `prefix_len` exceeds [`Self::BITS`].
+    fn mask_from_prefix_len(prefix_len: u8) -> Option<Self>;
+
+    /// Returns the prefix length if this address is a contiguous mask.
+    fn prefix_len_of_mask(self) -> Option<u8>;
+}
+
+impl MacInteger for MacAddr {
+    const BITS: u32 = 48;
+    const MAX: u64 = 0xffff_ffff_ffff;
+
+    #[inline]
+    fn to_u64(self) -> u64 {
+        MacAddr::to_u64(&self)
+    }
+
+    #[inline]
+    fn from_u64(v: u64) -> Self {
+        MacAddr::from_u64(v)
+    }
+
+    #[inline]
+    fn mask_from_prefix_len(prefix_len: u8) -> Option<Self> {
+        MacAddr::mask_from_prefix_len(prefix_len)
+    }
+
+    #[inline]
+    fn prefix_len_of_mask(self) -> Option<u8> {
+        MacAddr::prefix_len_of_mask(&self)
+    }
+}
+
+impl MacInteger for MacAddr8 {
+    const BITS: u32 = 64;
+    const MAX: u64 = u64::MAX;
+
+    #[inline]
+    fn to_u64(self) -> u64 {
+        MacAddr8::to_u64(&self)
+    }
+
+    #[inline]
+    fn from_u64(v: u64) -> Self {
+        MacAddr8::from_u64(v)
+    }
+
+    #[inline]
+    fn mask_from_prefix_len(prefix_len: u8) -> Option<Self> {
+        MacAddr8::mask_from_prefix_len(prefix_len)
+    }
+
+    #[inline]
+    fn prefix_len_of_mask(self) -> Option<u8> {
+        MacAddr8::prefix_len_of_mask(&self)
+    }
+}
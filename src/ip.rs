@@ -0,0 +1,135 @@
+use crate::addr::MacAddr;
+use crate::addr8::MacAddr8;
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+/// U/L (locally administered) bit of the first octet, inverted in modified EUI-64.
+const LOCAL_BIT: u8 = 0x02;
+
+/// `01:00:5e`, the IANA block for IPv4 multicast (RFC 1112).
+const IPV4_MULTICAST_OUI: [u8; 3] = [0x01, 0x00, 0x5e];
+
+/// `fe80::/64`.
+const LINK_LOCAL_PREFIX: u128 = 0xfe80 << 112;
+
+impl MacAddr {
+    /// Returns the RFC 4291 modified EUI-64 interface identifier.
+    ///
+    /// `ff:fe` is inserted in the middle and the U/L bit is inverted, so
+    /// `00:25:96:12:34:56` becomes `02:25:96:ff:fe:12:34:56`.
+    #[inline]
+    pub const fn to_modified_eui64(&self) -> MacAddr8 {
+        MacAddr8::from_eui48(*self).to_modified_eui64()
+    }
+
+    /// Returns the IPv6 link-local address (`fe80::/64`) formed from this address.
+    ///
+    /// ```
+    /// use mac_addr::MacAddr;
+    /// use std::net::Ipv6Addr;
+    ///
+    /// let mac = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+    /// let ll: Ipv6Addr = "fe80::225:96ff:fe12:3456".parse().unwrap();
+    /// assert_eq!(mac.to_ipv6_link_local(), ll);
+    /// assert_eq!(MacAddr::from_ipv6_eui64(&ll), Some(mac));
+    /// ```
+    #[inline]
+    pub const fn to_ipv6_link_local(&self) -> Ipv6Addr {
+        Ipv6Addr::from_bits(LINK_LOCAL_PREFIX | self.to_modified_eui64().to_u64() as u128)
+    }
+
+    /// Returns the SLAAC address formed from the first `prefix_len` bits of `prefix`.
+    ///
+    /// Bits of `prefix` past `prefix_len` are ignored. Returns `None` if
+    /// `prefix_len > 64`, since the interface identifier takes the low 64 bits.
+    #[inline]
+    pub const fn to_ipv6_with_prefix(&self, prefix: Ipv6Addr, prefix_len: u8) -> Option<Ipv6Addr> {
+        if prefix_len > 64 {
+            return None;
+        }
+        let mask = match u128::MAX.checked_shl(128 - prefix_len as u32) {
+            Some(m) => m,
+            None => 0,
+        };
+        let iid = self.to_modified_eui64().to_u64() as u128;
+        Some(Ipv6Addr::from_bits((prefix.to_bits() & mask) | iid))
+    }
+
+    /// Recovers the hardware address from an IPv6 address with a modified EUI-64 interface identifier.
+    ///
+    /// Returns `None` if the low 64 bits do not carry the `ff:fe` marker, as with
+    /// privacy or stable-opaque addresses.
+    #[inline]
+    pub const fn from_ipv6_eui64(addr: &Ipv6Addr) -> Option<MacAddr> {
+        MacAddr8::from_u64(addr.to_bits() as u64)
+            .to_modified_eui64()
+            .to_eui48()
+    }
+
+    /// Returns the Ethernet group address for an IPv4 multicast group (RFC 1112).
+    ///
+    /// The low 23 bits of the group are placed under `01:00:5e`, so 32 groups
+    /// share each address. Returns `None` unless `group` is in `224.0.0.0/4`.
+    #[inline]
+    pub const fn from_ipv4_multicast(group: Ipv4Addr) -> Option<MacAddr> {
+        if !group.is_multicast() {
+            return None;
+        }
+        let [_, b, c, d] = group.octets();
+        let [o0, o1, o2] = IPV4_MULTICAST_OUI;
+        Some(MacAddr(o0, o1, o2, b & 0x7f, c, d))
+    }
+
+    /// Returns the Ethernet group address for an IPv6 multicast group (RFC 2464).
+    ///
+    /// The low 32 bits of the group are placed under `33:33`. Returns `None`
+    /// unless `group` is in `ff00::/8`.
+    #[inline]
+    pub const fn from_ipv6_multicast(group: Ipv6Addr) -> Option<MacAddr> {
+        if !group.is_multicast() {
+            return None;
+        }
+        let [a, b, c, d] = (group.to_bits() as u32).to_be_bytes();
+        Some(MacAddr(0x33, 0x33, a, b, c, d))
+    }
+
+    /// Returns the 32 IPv4 multicast groups that map to this address, in ascending order.
+    ///
+    /// Returns `None` unless the address is in `01:00:5e:00:00:00/25`.
+    ///
+    /// ```
+    /// use mac_addr::MacAddr;
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let mac = MacAddr::from_ipv4_multicast(Ipv4Addr::new(239, 1, 2, 3)).unwrap();
+    /// let groups = mac.candidate_ipv4_groups().unwrap();
+    /// assert_eq!(groups[0], Ipv4Addr::new(224, 1, 2, 3));
+    /// assert_eq!(groups[31], Ipv4Addr::new(239, 129, 2, 3));
+    /// ```
+    pub const fn candidate_ipv4_groups(&self) -> Option<[Ipv4Addr; 32]> {
+        let [o0, o1, o2] = IPV4_MULTICAST_OUI;
+        if self.0 != o0 || self.1 != o1 || self.2 != o2 || self.3 & 0x80 != 0 {
+            return None;
+        }
+        let mut groups = [Ipv4Addr::UNSPECIFIED; 32];
+        let mut i = 0;
+        while i < 32 {
+            let first = 224 + (i >> 1) as u8;
+            let second = self.3 | ((i as u8 & 1) << 7);
+            groups[i] = Ipv4Addr::new(first, second, self.4, self.5);
+            i += 1;
+        }
+        Some(groups)
+    }
+}
+
+impl MacAddr8 {
+    /// Returns the RFC 4291 modified EUI-64 form, with the U/L bit inverted.
+    ///
+    /// The conversion is its own inverse.
+    #[inline]
+    pub const fn to_modified_eui64(&self) -> MacAddr8 {
+        let mut o = self.octets();
+        o[0] ^= LOCAL_BIT;
+        MacAddr8::from_octets(o)
+    }
+}
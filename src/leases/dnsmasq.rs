@@ -0,0 +1,106 @@
+use super::{ClientId, Lease, parse_hex_bytes};
+use crate::addr::MacAddr;
+use crate::error::{LeaseError, MalformedLease};
+use std::io::{BufRead, Lines};
+
+/// Returns a streaming parser for a dnsmasq lease file.
+///
+/// Each line is `expiry mac ip hostname client-id`, with `*` for an unknown
+/// hostname or client ID and an expiry of `0` for infinite leases. The
+/// `duid` line is skipped. DHCPv6 leases, which carry an IAID (`T`-prefixed
+/// for temporary addresses) instead of a MAC, and clients without a hardware
+/// address (`01-`) yield [`MalformedLease::NoHardwareAddress`]; non-Ethernet
+/// clients, written as `<htype>-<address>`, yield
+/// [`MalformedLease::NonEthernet`].
+///
+/// ```
+/// use mac_addr::leases;
+///
+/// let text = "1705579200 00:25:96:12:34:56 192.0.2.10 laptop 01:00:25:96:12:34:56\n";
+/// let lease = leases::parse_dnsmasq(text.as_bytes()).next().unwrap().unwrap();
+/// assert_eq!(lease.ip, "192.0.2.10".parse::<std::net::IpAddr>().unwrap());
+/// assert_eq!(lease.client_id, Some(leases::ClientId::Ethernet(lease.mac)));
+/// ```
+pub fn parse_dnsmasq<R: BufRead>(reader: R) -> DnsmasqLeases<R> {
+    DnsmasqLeases {
+        lines: reader.lines(),
+        line: 0,
+    }
+}
+
+/// Iterator returned by [`parse_dnsmasq`].
+#[derive(Debug)]
+pub struct DnsmasqLeases<R> {
+    lines: Lines<R>,
+    line: usize,
+}
+
+impl<R: BufRead> Iterator for DnsmasqLeases<R> {
+    type Item = Result<Lease, LeaseError>;
+
+    fn next(&mut self) -> Option<Result<Lease, LeaseError>> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            self.line += 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("duid ") {
+                continue;
+            }
+            return Some(parse_line(line).map_err(|kind| LeaseError::Malformed {
+                line: self.line,
+                kind,
+            }));
+        }
+    }
+}
+
+fn parse_line(line: &str) -> Result<Lease, MalformedLease> {
+    let mut fields = line.split_whitespace();
+    let mut next = || fields.next().ok_or(MalformedLease::MissingField);
+    let expiry: u64 = next()?.parse().map_err(|_| MalformedLease::InvalidTime)?;
+    let hw = next()?;
+    let ip = next()?.parse().map_err(|_| MalformedLease::InvalidIp)?;
+    let hostname = next()?;
+    let client_id = next()?;
+
+    let mac = parse_hw(hw)?;
+    Ok(Lease {
+        mac,
+        ip,
+        hostname: (hostname != "*").then(|| hostname.to_string()),
+        expires: (expiry != 0).then_some(expiry),
+        client_id: match client_id {
+            "*" => None,
+            id => ClientId::from_bytes(&parse_hex_bytes(id)?),
+        },
+    })
+}
+
+fn parse_hw(hw: &str) -> Result<MacAddr, MalformedLease> {
+    // A DHCPv6 IAID, prefixed with `T` for temporary addresses.
+    let iaid = hw.strip_prefix('T').unwrap_or(hw);
+    if !iaid.is_empty() && iaid.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(MalformedLease::NoHardwareAddress);
+    }
+    // `<htype>-<address>`, written for non-Ethernet types and empty addresses.
+    if let Some((htype, rest)) = hw.split_once('-')
+        && htype.len() == 2
+        && !rest.contains('-')
+    {
+        let htype = u8::from_str_radix(htype, 16).map_err(|_| MalformedLease::InvalidAddress)?;
+        let octets = match rest {
+            "" => Vec::new(),
+            rest => parse_hex_bytes(rest).map_err(|_| MalformedLease::InvalidAddress)?,
+        };
+        return match (htype, octets.len()) {
+            (_, 0) => Err(MalformedLease::NoHardwareAddress),
+            (1, 6) => MacAddr::try_from(&octets[..]).map_err(|_| MalformedLease::InvalidAddress),
+            (1, _) => Err(MalformedLease::InvalidAddress),
+            (htype, _) => Err(MalformedLease::NonEthernet(htype)),
+        };
+    }
+    hw.parse().map_err(|_| MalformedLease::InvalidAddress)
+}
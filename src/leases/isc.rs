@@ -0,0 +1,260 @@
+use super::{ClientId, Lease, parse_hex_bytes, unix_time};
+use crate::addr::MacAddr;
+use crate::error::{LeaseError, MalformedLease};
+use std::io::{BufRead, Lines};
+use std::net::IpAddr;
+
+/// Returns a streaming parser for an ISC `dhcpd.leases` file.
+///
+/// Only `lease <ip> { ... }` blocks are read; other declarations such as
+/// `server-duid` or failover state are skipped. Times must be in the default
+/// UTC format (`ends 4 2024/01/18 12:00:00;`) or `epoch` format.
+///
+/// ```
+/// use mac_addr::leases;
+///
+/// let text = r#"
+/// lease 192.0.2.10 {
+///   ends 4 2024/01/18 12:00:00;
+///   hardware ethernet 00:25:96:12:34:56;
+///   client-hostname "laptop";
+/// }
+/// "#;
+/// let lease = leases::parse_isc(text.as_bytes()).next().unwrap().unwrap();
+/// assert_eq!(lease.mac, "00:25:96:12:34:56".parse().unwrap());
+/// assert_eq!(lease.hostname.as_deref(), Some("laptop"));
+/// assert_eq!(lease.expires, Some(1705579200));
+/// ```
+pub fn parse_isc<R: BufRead>(reader: R) -> IscLeases<R> {
+    IscLeases {
+        lines: reader.lines(),
+        line: 0,
+    }
+}
+
+/// Iterator returned by [`parse_isc`].
+#[derive(Debug)]
+pub struct IscLeases<R> {
+    lines: Lines<R>,
+    line: usize,
+}
+
+/// Fields collected from a `lease` block.
+#[derive(Default)]
+struct Block {
+    ip: Option<IpAddr>,
+    mac: Option<Result<MacAddr, MalformedLease>>,
+    hostname: Option<String>,
+    expires: Option<Option<u64>>,
+    client_id: Option<ClientId>,
+    error: Option<MalformedLease>,
+}
+
+impl<R: BufRead> IscLeases<R> {
+    fn next_line(&mut self) -> Option<Result<String, LeaseError>> {
+        let line = self.lines.next()?;
+        self.line += 1;
+        Some(line.map_err(LeaseError::from))
+    }
+}
+
+impl<R: BufRead> Iterator for IscLeases<R> {
+    type Item = Result<Lease, LeaseError>;
+
+    fn next(&mut self) -> Option<Result<Lease, LeaseError>> {
+        loop {
+            let line = match self.next_line()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+            let line = strip_comment(&line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let start = self.line;
+            let Some(header) = line.strip_prefix("lease ") else {
+                // Skip other declarations, including nested blocks.
+                if line.ends_with('{')
+                    && let Err(e) = self.skip_block()
+                {
+                    return Some(Err(e));
+                }
+                continue;
+            };
+            let mut block = Block::default();
+            match header.trim_end_matches('{').trim().parse() {
+                Ok(ip) => block.ip = Some(ip),
+                Err(_) => block.error = Some(MalformedLease::InvalidIp),
+            }
+            loop {
+                let line = match self.next_line() {
+                    Some(Ok(line)) => line,
+                    Some(Err(e)) => return Some(Err(e)),
+                    None => break,
+                };
+                let stmt = strip_comment(&line).trim();
+                if stmt == "}" {
+                    break;
+                }
+                if stmt.ends_with('{') {
+                    if let Err(e) = self.skip_block() {
+                        return Some(Err(e));
+                    }
+                    continue;
+                }
+                if let Err(kind) = block.statement(stmt.trim_end_matches(';').trim()) {
+                    block.error.get_or_insert(kind);
+                }
+            }
+            return Some(
+                block
+                    .finish()
+                    .map_err(|kind| LeaseError::Malformed { line: start, kind }),
+            );
+        }
+    }
+}
+
+impl<R: BufRead> IscLeases<R> {
+    /// Consumes lines up to the `}` closing a block whose `{` was just read.
+    fn skip_block(&mut self) -> Result<(), LeaseError> {
+        let mut depth = 1;
+        while depth > 0 {
+            let Some(line) = self.next_line() else {
+                return Ok(());
+            };
+            let line = line?;
+            let line = strip_comment(&line);
+            depth += line.matches('{').count();
+            depth -= line.matches('}').count().min(depth);
+        }
+        Ok(())
+    }
+}
+
+impl Block {
+    fn statement(&mut self, stmt: &str) -> Result<(), MalformedLease> {
+        let (keyword, rest) = stmt.split_once(char::is_whitespace).unwrap_or((stmt, ""));
+        let rest = rest.trim();
+        match keyword {
+            "hardware" => {
+                let (htype, addr) = rest
+                    .split_once(char::is_whitespace)
+                    .ok_or(MalformedLease::MissingField)?;
+                self.mac = Some(match htype {
+                    "ethernet" => addr
+                        .trim()
+                        .parse()
+                        .map_err(|_| MalformedLease::InvalidAddress),
+                    "token-ring" => Err(MalformedLease::NonEthernet(6)),
+                    "fddi" => Err(MalformedLease::NonEthernet(8)),
+                    "infiniband" => Err(MalformedLease::NonEthernet(32)),
+                    _ => Err(MalformedLease::InvalidAddress),
+                });
+            }
+            "ends" => self.expires = Some(parse_time(rest)?),
+            "client-hostname" => {
+                self.hostname = Some(String::from_utf8_lossy(&unquote(rest)?).into_owned())
+            }
+            "uid" => {
+                let raw = if rest.starts_with('"') {
+                    unquote(rest)?
+                } else {
+                    parse_hex_bytes(rest)?
+                };
+                self.client_id = ClientId::from_bytes(&raw);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Lease, MalformedLease> {
+        if let Some(kind) = self.error {
+            return Err(kind);
+        }
+        let mac = self.mac.ok_or(MalformedLease::NoHardwareAddress)??;
+        Ok(Lease {
+            mac,
+            ip: self.ip.ok_or(MalformedLease::InvalidIp)?,
+            hostname: self.hostname,
+            expires: self.expires.flatten(),
+            client_id: self.client_id,
+        })
+    }
+}
+
+/// Parses `4 2024/01/18 12:00:00`, `epoch 1705579200` or `never`.
+fn parse_time(s: &str) -> Result<Option<u64>, MalformedLease> {
+    let fields: Vec<&str> = s.split_whitespace().collect();
+    match fields[..] {
+        ["never"] => Ok(None),
+        ["epoch", secs] => secs
+            .parse()
+            .map(Some)
+            .map_err(|_| MalformedLease::InvalidTime),
+        [_weekday, date, time] => {
+            let num = |s: &str| s.parse::<u32>().map_err(|_| MalformedLease::InvalidTime);
+            let mut d = date.splitn(3, '/');
+            let mut t = time.splitn(3, ':');
+            let next = |it: &mut std::str::SplitN<'_, char>| {
+                it.next().ok_or(MalformedLease::InvalidTime).and_then(num)
+            };
+            let (y, mo, da) = (next(&mut d)?, next(&mut d)?, next(&mut d)?);
+            let (h, mi, se) = (next(&mut t)?, next(&mut t)?, next(&mut t)?);
+            unix_time(y as i64, mo, da, h, mi, se)
+                .map(Some)
+                .ok_or(MalformedLease::InvalidTime)
+        }
+        _ => Err(MalformedLease::InvalidTime),
+    }
+}
+
+/// Decodes a quoted string with ISC's octal (`\001`) and backslash escapes.
+fn unquote(s: &str) -> Result<Vec<u8>, MalformedLease> {
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or(MalformedLease::MissingField)?;
+    let mut out = Vec::with_capacity(inner.len());
+    let mut bytes = inner.bytes();
+    while let Some(b) = bytes.next() {
+        if b != b'\\' {
+            out.push(b);
+            continue;
+        }
+        match bytes.next().ok_or(MalformedLease::InvalidHex)? {
+            d @ b'0'..=b'7' => {
+                let mut v = (d - b'0') as u32;
+                for _ in 0..2 {
+                    match bytes.next() {
+                        Some(d @ b'0'..=b'7') => v = v * 8 + (d - b'0') as u32,
+                        _ => return Err(MalformedLease::InvalidHex),
+                    }
+                }
+                out.push(u8::try_from(v).map_err(|_| MalformedLease::InvalidHex)?);
+            }
+            other => out.push(other),
+        }
+    }
+    Ok(out)
+}
+
+fn strip_comment(line: &str) -> &str {
+    // `#` inside quoted strings is not a comment.
+    let mut quoted = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match c {
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
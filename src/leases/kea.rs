@@ -0,0 +1,147 @@
+use super::{ClientId, Lease, parse_hex_bytes};
+use crate::error::{LeaseError, MalformedLease};
+use std::io::{BufRead, Lines};
+
+/// `valid_lifetime` Kea uses for leases that never expire.
+const INFINITE_LIFETIME: u64 = 0xffff_ffff;
+
+/// Returns a streaming parser for a Kea memfile lease CSV (`kea-leases4.csv` or `kea-leases6.csv`).
+///
+/// Columns are located by the header line, so both the DHCPv4 and DHCPv6
+/// layouts are read. DHCPv6 leases without a recorded `hwaddr` yield
+/// [`MalformedLease::NoHardwareAddress`], and those with an `hwtype` other
+/// than 1 yield [`MalformedLease::NonEthernet`].
+///
+/// Kea only appends to the memfile between cleanups, so one address can
+/// appear on several rows; the last row for an address supersedes the
+/// earlier ones. Rows are yielded in file order without merging, so callers
+/// that want the current table should keep the last lease per address.
+/// Reclaimed (expired or released) leases stay in the file with a
+/// `valid_lifetime` of 0 or a `state` of 2; they are still yielded, with
+/// `expires` taken from the `expire` column.
+///
+/// ```
+/// use mac_addr::leases;
+///
+/// let text = "address,hwaddr,client_id,valid_lifetime,expire,subnet_id,fqdn_fwd,fqdn_rev,hostname,state,user_context,pool_id\n\
+///             192.0.2.10,00:25:96:12:34:56,,3600,1705579200,1,0,0,laptop,0,,0\n";
+/// let lease = leases::parse_kea(text.as_bytes()).next().unwrap().unwrap();
+/// assert_eq!(lease.hostname.as_deref(), Some("laptop"));
+/// assert_eq!(lease.expires, Some(1705579200));
+/// ```
+pub fn parse_kea<R: BufRead>(reader: R) -> KeaLeases<R> {
+    KeaLeases {
+        lines: reader.lines(),
+        line: 0,
+        columns: None,
+    }
+}
+
+/// Iterator returned by [`parse_kea`].
+#[derive(Debug)]
+pub struct KeaLeases<R> {
+    lines: Lines<R>,
+    line: usize,
+    columns: Option<Columns>,
+}
+
+/// Positions of the columns the parser reads.
+#[derive(Debug)]
+struct Columns {
+    address: usize,
+    hwaddr: usize,
+    client_id: Option<usize>,
+    valid_lifetime: Option<usize>,
+    expire: Option<usize>,
+    hostname: Option<usize>,
+    hwtype: Option<usize>,
+}
+
+impl Columns {
+    fn from_header(header: &str) -> Option<Columns> {
+        let names: Vec<&str> = header.split(',').map(str::trim).collect();
+        let find = |name: &str| names.iter().position(|&n| n == name);
+        Some(Columns {
+            address: find("address")?,
+            hwaddr: find("hwaddr")?,
+            client_id: find("client_id"),
+            valid_lifetime: find("valid_lifetime"),
+            expire: find("expire"),
+            hostname: find("hostname"),
+            hwtype: find("hwtype"),
+        })
+    }
+
+    fn parse(&self, line: &str) -> Result<Lease, MalformedLease> {
+        let fields: Vec<&str> = line.split(',').collect();
+        let field = |i: usize| fields.get(i).copied().ok_or(MalformedLease::MissingField);
+        let optional = |i: Option<usize>| match i {
+            Some(i) => field(i).map(|f| Some(f).filter(|f| !f.is_empty())),
+            None => Ok(None),
+        };
+
+        let ip = field(self.address)?
+            .parse()
+            .map_err(|_| MalformedLease::InvalidIp)?;
+        if let Some(hwtype) = optional(self.hwtype)? {
+            let hwtype: u8 = hwtype.parse().map_err(|_| MalformedLease::InvalidAddress)?;
+            if hwtype != 1 {
+                return Err(MalformedLease::NonEthernet(hwtype));
+            }
+        }
+        let mac = match field(self.hwaddr)? {
+            "" => return Err(MalformedLease::NoHardwareAddress),
+            hw => hw.parse().map_err(|_| MalformedLease::InvalidAddress)?,
+        };
+        let time = |s: Option<&str>| -> Result<Option<u64>, MalformedLease> {
+            s.map(|s| s.parse().map_err(|_| MalformedLease::InvalidTime))
+                .transpose()
+        };
+        let lifetime = time(optional(self.valid_lifetime)?)?;
+        let expires = match lifetime {
+            Some(INFINITE_LIFETIME) => None,
+            _ => time(optional(self.expire)?)?,
+        };
+        Ok(Lease {
+            mac,
+            ip,
+            // Kea escapes commas in text fields.
+            hostname: optional(self.hostname)?.map(|h| h.replace("&#x2c", ",")),
+            expires,
+            client_id: match optional(self.client_id)? {
+                Some(id) => ClientId::from_bytes(&parse_hex_bytes(id)?),
+                None => None,
+            },
+        })
+    }
+}
+
+impl<R: BufRead> Iterator for KeaLeases<R> {
+    type Item = Result<Lease, LeaseError>;
+
+    fn next(&mut self) -> Option<Result<Lease, LeaseError>> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            self.line += 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let malformed = |kind| LeaseError::Malformed {
+                line: self.line,
+                kind,
+            };
+            let Some(columns) = &self.columns else {
+                match Columns::from_header(line) {
+                    Some(columns) => self.columns = Some(columns),
+                    None => return Some(Err(malformed(MalformedLease::MissingField))),
+                }
+                continue;
+            };
+            return Some(columns.parse(line).map_err(malformed));
+        }
+    }
+}
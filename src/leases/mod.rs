@@ -0,0 +1,135 @@
+//! DHCP lease file parsers keyed by [`MacAddr`].
+//!
+//! Supports ISC `dhcpd.leases`, dnsmasq `dnsmasq.leases` and the Kea memfile
+//! CSV. Each parser is an iterator that yields one [`Lease`] or
+//! [`LeaseError`](crate::LeaseError) per entry and keeps going after malformed
+//! entries, so callers can skip DHCPv6 or non-Ethernet leases
+//! ([`MalformedLease::NoHardwareAddress`], [`MalformedLease::NonEthernet`])
+//! and keep the rest. Entries are yielded in file order; in ISC files a later
+//! entry for the same address supersedes an earlier one.
+
+use crate::addr::MacAddr;
+use crate::error::MalformedLease;
+use std::net::IpAddr;
+
+mod dnsmasq;
+mod isc;
+mod kea;
+
+pub use dnsmasq::{DnsmasqLeases, parse_dnsmasq};
+pub use isc::{IscLeases, parse_isc};
+pub use kea::{KeaLeases, parse_kea};
+
+/// A DHCP lease.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Lease {
+    /// Client hardware address.
+    pub mac: MacAddr,
+    /// Leased address.
+    pub ip: IpAddr,
+    /// Hostname supplied by the client, if any.
+    pub hostname: Option<String>,
+    /// Expiry as seconds since the Unix epoch, or `None` for leases that never expire.
+    pub expires: Option<u64>,
+    /// DHCP client identifier (option 61), if any.
+    pub client_id: Option<ClientId>,
+}
+
+/// DHCP client identifier (option 61), decoded by its leading type byte.
+///
+/// ```
+/// use mac_addr::MacAddr;
+/// use mac_addr::leases::ClientId;
+///
+/// let id = ClientId::from_bytes(&[0x01, 0x00, 0x25, 0x96, 0x12, 0x34, 0x56]).unwrap();
+/// assert_eq!(id, ClientId::Ethernet(MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56)));
+/// assert_eq!(ClientId::from_bytes(b"\0host"), Some(ClientId::Other(0, b"host".to_vec())));
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ClientId {
+    /// Type 1: an Ethernet hardware address.
+    Ethernet(MacAddr),
+    /// Type 255 (RFC 4361): an IAID followed by a DHCPv6 DUID.
+    Duid {
+        /// Identity association identifier.
+        iaid: u32,
+        /// DHCP unique identifier.
+        duid: Vec<u8>,
+    },
+    /// Any other type, or a type 1 or 255 identifier of unexpected length,
+    /// with the bytes after the type byte. Type 0 usually holds a text string.
+    Other(u8, Vec<u8>),
+}
+
+impl ClientId {
+    /// Decodes a raw client identifier. Returns `None` for an empty identifier.
+    pub fn from_bytes(bytes: &[u8]) -> Option<ClientId> {
+        let (&kind, rest) = bytes.split_first()?;
+        Some(match (kind, rest.len()) {
+            (1, 6) => ClientId::Ethernet(MacAddr::try_from(rest).ok()?),
+            (0xff, 5..) => ClientId::Duid {
+                iaid: u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]),
+                duid: rest[4..].to_vec(),
+            },
+            _ => ClientId::Other(kind, rest.to_vec()),
+        })
+    }
+
+    /// Returns the raw identifier, including the type byte.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        match self {
+            ClientId::Ethernet(mac) => {
+                out.push(1);
+                out.extend_from_slice(&mac.octets());
+            }
+            ClientId::Duid { iaid, duid } => {
+                out.push(0xff);
+                out.extend_from_slice(&iaid.to_be_bytes());
+                out.extend_from_slice(duid);
+            }
+            ClientId::Other(kind, rest) => {
+                out.push(*kind);
+                out.extend_from_slice(rest);
+            }
+        }
+        out
+    }
+}
+
+/// Parses colon-separated hex (`01:0:25:96`) into bytes. Octets may have one or two digits.
+fn parse_hex_bytes(s: &str) -> Result<Vec<u8>, MalformedLease> {
+    s.split(':')
+        .map(|part| match part.len() {
+            1 | 2 => u8::from_str_radix(part, 16).map_err(|_| MalformedLease::InvalidHex),
+            _ => Err(MalformedLease::InvalidHex),
+        })
+        .collect()
+}
+
+/// Converts a UTC calendar date and time to seconds since the Unix epoch.
+fn unix_time(year: i64, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> Option<u64> {
+    if !(1..=12).contains(&month) || hour > 23 || min > 59 || sec > 60 {
+        return None;
+    }
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let month_len = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    if !(1..=month_len).contains(&day) {
+        return None;
+    }
+    // Days from civil date (H. Hinnant), with March as the first month of the year.
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+    let secs = days * 86400 + hour as i64 * 3600 + min as i64 * 60 + sec as i64;
+    u64::try_from(secs).ok()
+}
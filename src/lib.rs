@@ -10,8 +10,56 @@ extern crate serde;
 mod addr; 
 // EUI-64 (8 bytes)
 mod addr8; 
+mod anonymize;
+mod derive;
 mod error; 
+pub mod ethernet;
+#[cfg(feature = "std")]
+pub mod ethers;
+mod format;
+mod hw;
+mod int;
+mod ip;
+#[cfg(feature = "std")]
+pub mod leases;
+mod macros;
+mod net;
+mod ops;
+mod oui;
+mod parse;
+mod privacy;
+#[cfg(feature = "rand")]
+mod random;
+mod range;
+mod siphash;
+mod slap;
+#[cfg(all(feature = "sys", target_os = "linux"))]
+pub mod sys;
+pub mod well_known;
+pub mod wol;
 
 pub use addr::MacAddr;
 pub use addr8::MacAddr8;
-pub use error::ParseMacAddrError;
+pub use anonymize::{Anonymizer, Redacted};
+pub use error::{EthernetError, MacNetError, ParseErrorKind, ParseMacAddrError, WolError};
+#[cfg(feature = "std")]
+pub use error::{
+    EthersError, LeaseError, MalformedEthersLine, MalformedLease, MalformedLine, RegistryError,
+};
+#[cfg(all(feature = "sys", target_os = "linux"))]
+pub use error::ArpParseError;
+pub use format::{FormattedMac, MacFormat, Separator};
+pub use hw::HwAddr;
+pub use int::MacInteger;
+pub use net::{MacNet, Subnets};
+pub use oui::{Organization, Registry};
+#[cfg(feature = "std")]
+pub use oui::OuiRegistry;
+pub use parse::{MacNotation, ParseMode};
+pub use privacy::{Hypervisor, PrivacyClass};
+pub use range::{MacRange, RangeChunks};
+pub use slap::SlapQuadrant;
+pub use well_known::WellKnown;
+
+#[doc(hidden)]
+pub use macros::invalid_literal as __invalid_literal;
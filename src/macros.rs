@@ -0,0 +1,69 @@
+use crate::error::{ParseErrorKind, ParseMacAddrError};
+
+/// Parses a [`MacAddr`](crate::MacAddr) at compile time.
+///
+/// Accepts every notation supported by [`MacAddr::parse_with_notation`](crate::MacAddr::parse_with_notation).
+/// An invalid literal fails the build.
+///
+/// ```
+/// use mac_addr::{MacAddr, mac};
+///
+/// const LLDP_MULTICAST: MacAddr = mac!("01:80:c2:00:00:0e");
+/// assert_eq!(LLDP_MULTICAST.octets(), [0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e]);
+/// ```
+///
+/// ```compile_fail
+/// const BAD: mac_addr::MacAddr = mac_addr::mac!("01:80:c2:00:00");
+/// ```
+#[macro_export]
+macro_rules! mac {
+    ($s:expr) => {
+        const {
+            match $crate::MacAddr::parse_with_mode($s, $crate::ParseMode::Standard) {
+                Ok(mac) => mac,
+                Err(e) => $crate::__invalid_literal(e),
+            }
+        }
+    };
+}
+
+/// Parses a [`MacAddr8`](crate::MacAddr8) at compile time.
+///
+/// An invalid literal fails the build.
+///
+/// ```
+/// use mac_addr::{MacAddr8, mac8};
+///
+/// const EUI64: MacAddr8 = mac8!("0025.96ff.fe12.3456");
+/// assert_eq!(EUI64.octets(), [0x00, 0x25, 0x96, 0xff, 0xfe, 0x12, 0x34, 0x56]);
+/// ```
+#[macro_export]
+macro_rules! mac8 {
+    ($s:expr) => {
+        const {
+            match $crate::MacAddr8::parse_with_mode($s, $crate::ParseMode::Standard) {
+                Ok(mac) => mac,
+                Err(e) => $crate::__invalid_literal(e),
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+pub const fn invalid_literal(e: ParseMacAddrError) -> ! {
+    match e.kind() {
+        ParseErrorKind::TooManyComponents => {
+            panic!("invalid MAC address literal: too many components")
+        }
+        ParseErrorKind::TooFewComponents => {
+            panic!("invalid MAC address literal: too few components")
+        }
+        ParseErrorKind::InvalidComponent => {
+            panic!("invalid MAC address literal: invalid component")
+        }
+        ParseErrorKind::MixedSeparators => panic!("invalid MAC address literal: mixed separators"),
+        ParseErrorKind::UnexpectedWhitespace => {
+            panic!("invalid MAC address literal: unexpected whitespace")
+        }
+    }
+}
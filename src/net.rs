@@ -0,0 +1,190 @@
+use crate::error::{MacNetError, ParseMacAddrError};
+use crate::int::MacInteger;
+use crate::range::MacRange;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::str::FromStr;
+
+/// Returns the network mask for `prefix_len` in the low `A::BITS` bits.
+///
+/// Callers validate `prefix_len` first; anything longer yields the full mask.
+#[inline]
+fn mask_bits<A: MacInteger>(prefix_len: u8) -> u64 {
+    A::mask_from_prefix_len(prefix_len).map_or(A::MAX, A::to_u64)
+}
+
+/// Block of MAC addresses sharing a common prefix, written like CIDR (`00:25:96:00:00:00/24`).
+///
+/// IEEE MA-L, MA-M and MA-S assignments are `/24`, `/28` and `/36` blocks respectively.
+///
+/// ```
+/// use mac_addr::{MacAddr, MacNet};
+///
+/// let ma_m: MacNet<MacAddr> = "00:25:96:12:30:00/ff:ff:ff:ff:f0:00".parse().unwrap();
+/// assert_eq!(ma_m.prefix_len(), 36);
+/// assert!(ma_m.contains("00:25:96:12:3f:ff".parse().unwrap()));
+/// assert_eq!(ma_m.hosts().num_addresses(), 4096);
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct MacNet<A> {
+    addr: A,
+    prefix_len: u8,
+}
+
+impl<A: MacInteger> MacNet<A> {
+    /// Constructs a block from an address and a prefix length in bits.
+    ///
+    /// Host bits in `addr` are kept; use [`MacNet::trunc`] to clear them.
+    #[inline]
+    pub fn new(addr: A, prefix_len: u8) -> Result<MacNet<A>, MacNetError> {
+        if prefix_len as u32 > A::BITS {
+            return Err(MacNetError::InvalidPrefixLength);
+        }
+        Ok(MacNet { addr, prefix_len })
+    }
+
+    /// Constructs a block from an address and a contiguous network mask.
+    pub fn with_mask(addr: A, mask: A) -> Result<MacNet<A>, MacNetError> {
+        let prefix_len = mask
+            .prefix_len_of_mask()
+            .ok_or(MacNetError::NonContiguousMask)?;
+        MacNet::new(addr, prefix_len)
+    }
+
+    /// Returns the address this block was constructed with.
+    #[inline]
+    pub fn addr(&self) -> A {
+        self.addr
+    }
+
+    /// Returns the prefix length in bits.
+    #[inline]
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns the network mask (`ff:ff:ff:00:00:00` for a `/24`).
+    #[inline]
+    pub fn mask(&self) -> A {
+        A::from_u64(mask_bits::<A>(self.prefix_len))
+    }
+
+    /// Returns the first address of the block (all host bits cleared).
+    #[inline]
+    pub fn network(&self) -> A {
+        A::from_u64(self.addr.to_u64() & mask_bits::<A>(self.prefix_len))
+    }
+
+    /// Returns the last address of the block (all host bits set).
+    #[inline]
+    pub fn broadcast(&self) -> A {
+        A::from_u64(self.addr.to_u64() | (A::MAX & !mask_bits::<A>(self.prefix_len)))
+    }
+
+    /// Returns the same block with host bits cleared.
+    #[inline]
+    pub fn trunc(&self) -> MacNet<A> {
+        MacNet {
+            addr: self.network(),
+            prefix_len: self.prefix_len,
+        }
+    }
+
+    /// Returns `true` if `addr` belongs to this block.
+    #[inline]
+    pub fn contains(&self, addr: A) -> bool {
+        let mask = mask_bits::<A>(self.prefix_len);
+        addr.to_u64() & mask == self.addr.to_u64() & mask
+    }
+
+    /// Returns `true` if `other` lies entirely within this block.
+    #[inline]
+    pub fn contains_net(&self, other: &MacNet<A>) -> bool {
+        other.prefix_len >= self.prefix_len && self.contains(other.addr)
+    }
+
+    /// Returns every address in the block, from [`network`](MacNet::network) to
+    /// [`broadcast`](MacNet::broadcast).
+    #[inline]
+    pub fn hosts(&self) -> MacRange<A> {
+        MacRange::new(self.network(), self.broadcast())
+    }
+
+    /// Returns an iterator over the sub-blocks of length `new_prefix_len`.
+    ///
+    /// Fails if `new_prefix_len` is shorter than this block's prefix or longer
+    /// than the address width.
+    pub fn subnets(&self, new_prefix_len: u8) -> Result<Subnets<A>, MacNetError> {
+        if new_prefix_len < self.prefix_len || new_prefix_len as u32 > A::BITS {
+            return Err(MacNetError::InvalidPrefixLength);
+        }
+        Ok(Subnets {
+            next: self.network().to_u64(),
+            last: self.broadcast().to_u64() & mask_bits::<A>(new_prefix_len),
+            prefix_len: new_prefix_len,
+            done: false,
+            _addr: core::marker::PhantomData,
+        })
+    }
+}
+
+impl<A: MacInteger + fmt::Display> fmt::Display for MacNet<A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
+impl<A: MacInteger + FromStr<Err = ParseMacAddrError>> FromStr for MacNet<A> {
+    type Err = MacNetError;
+
+    /// Parses `addr/len` or `addr/mask`, where `addr` and `mask` use any notation
+    /// accepted by the address type.
+    fn from_str(s: &str) -> Result<MacNet<A>, MacNetError> {
+        let (addr, prefix) = s.split_once('/').ok_or(MacNetError::MissingPrefix)?;
+        let addr: A = addr.parse()?;
+        // Up to three digits is a prefix length, so `/100` and `/024` are
+        // reported as lengths. Longer suffixes are masks: bare masks such as
+        // `800000000000` (a `/1`) can consist of digits only.
+        if !prefix.is_empty() && prefix.len() <= 3 && prefix.bytes().all(|b| b.is_ascii_digit()) {
+            let len = prefix
+                .parse::<u8>()
+                .map_err(|_| MacNetError::InvalidPrefixLength)?;
+            MacNet::new(addr, len)
+        } else {
+            let mask: A = prefix.parse()?;
+            MacNet::with_mask(addr, mask)
+        }
+    }
+}
+
+/// Iterator returned by [`MacNet::subnets`].
+#[derive(Clone, Debug)]
+pub struct Subnets<A> {
+    next: u64,
+    last: u64,
+    prefix_len: u8,
+    done: bool,
+    _addr: core::marker::PhantomData<A>,
+}
+
+impl<A: MacInteger> Iterator for Subnets<A> {
+    type Item = MacNet<A>;
+
+    fn next(&mut self) -> Option<MacNet<A>> {
+        if self.done {
+            return None;
+        }
+        let net = MacNet {
+            addr: A::from_u64(self.next),
+            prefix_len: self.prefix_len,
+        };
+        if self.next == self.last {
+            self.done = true;
+        } else {
+            self.next += 1 << (A::BITS - self.prefix_len as u32);
+        }
+        Some(net)
+    }
+}
+
+impl<A: MacInteger> FusedIterator for Subnets<A> {}
@@ -0,0 +1,41 @@
+use crate::addr::MacAddr;
+use crate::addr8::MacAddr8;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+// Bitwise operators work on the integer form; `from_u64` drops any bits above the address width.
+macro_rules! bit_ops {
+    ($t:ident) => {
+        impl Not for $t {
+            type Output = $t;
+
+            #[inline]
+            fn not(self) -> $t {
+                $t::from_u64(!self.to_u64())
+            }
+        }
+
+        bit_ops!(@binary $t, BitAnd, bitand, BitAndAssign, bitand_assign, &);
+        bit_ops!(@binary $t, BitOr, bitor, BitOrAssign, bitor_assign, |);
+        bit_ops!(@binary $t, BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+    };
+    (@binary $t:ident, $tr:ident, $f:ident, $tr_assign:ident, $f_assign:ident, $op:tt) => {
+        impl $tr for $t {
+            type Output = $t;
+
+            #[inline]
+            fn $f(self, rhs: $t) -> $t {
+                $t::from_u64(self.to_u64() $op rhs.to_u64())
+            }
+        }
+
+        impl $tr_assign for $t {
+            #[inline]
+            fn $f_assign(&mut self, rhs: $t) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+bit_ops!(MacAddr);
+bit_ops!(MacAddr8);
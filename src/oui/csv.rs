@@ -0,0 +1,48 @@
+//! IEEE registry CSV helpers shared by the build script and the runtime loader.
+//!
+//! This file is compiled both as part of the library and, via `#[path]`, by
+//! `build.rs`, so it must not refer to anything else in the crate.
+
+/// Splits a CSV record into raw fields, keeping any surrounding quotes.
+///
+/// Returns `None` if a quoted field is not terminated.
+pub fn split_fields(line: &str) -> Option<Vec<&str>> {
+    let mut fields = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    for (i, b) in line.bytes().enumerate() {
+        match b {
+            b'"' => in_quotes = !in_quotes,
+            b',' if !in_quotes => {
+                fields.push(&line[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if in_quotes {
+        return None;
+    }
+    fields.push(&line[start..]);
+    Some(fields)
+}
+
+/// Removes surrounding quotes and un-escapes doubled quotes (`""`).
+pub fn unquote(field: &str) -> String {
+    let field = field.trim();
+    match field.strip_prefix('"').and_then(|f| f.strip_suffix('"')) {
+        Some(inner) => inner.replace("\"\"", "\""),
+        None => field.to_string(),
+    }
+}
+
+/// Parses a hex assignment such as `002596` (MA-L), `0055DA1` (MA-M) or
+/// `70B3D5001` (MA-S) into its 48-bit network value and prefix length.
+pub fn parse_assignment(hex: &str) -> Option<(u64, u8)> {
+    if hex.is_empty() || hex.len() > 12 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let bits = (hex.len() * 4) as u8;
+    let value = u64::from_str_radix(hex, 16).ok()?;
+    Some((value << (48 - bits as u32), bits))
+}
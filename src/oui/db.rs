@@ -0,0 +1,52 @@
+//! Registry tables generated by `build.rs` from the IEEE CSV downloads.
+//!
+//! Each prefix length has a sorted key array and a parallel value array.
+//! A value packs the registry in its low 3 bits and an index into the
+//! deduplicated organization names above them; the names are stored as one
+//! string (`NAME_POOL`) with the end offset of each in `NAME_ENDS`.
+
+use super::{Organization, Registry};
+use crate::addr::MacAddr;
+
+include!(concat!(env!("OUT_DIR"), "/oui_db.rs"));
+
+/// Decodes the registry tag, which `build.rs` takes from the declaration order of [`Registry`].
+#[inline]
+fn registry(tag: u32) -> Registry {
+    match tag {
+        0 => Registry::MaL,
+        1 => Registry::MaM,
+        2 => Registry::MaS,
+        3 => Registry::Cid,
+        _ => Registry::Iab,
+    }
+}
+
+#[inline]
+fn name(index: usize) -> &'static str {
+    let start = match index {
+        0 => 0,
+        _ => NAME_ENDS[index - 1] as usize,
+    };
+    &NAME_POOL[start..NAME_ENDS[index] as usize]
+}
+
+#[inline]
+fn find<K: Ord>(keys: &[K], values: &[u32], key: K) -> Option<(Registry, &'static str)> {
+    let value = values[keys.binary_search(&key).ok()?];
+    Some((registry(value & 0x7), name((value >> 3) as usize)))
+}
+
+/// Looks up the most specific assignment covering `mac`.
+pub(crate) fn lookup(mac: MacAddr) -> Option<Organization<'static>> {
+    let v = mac.to_u64();
+    let hit = find(&KEYS_36, &VALUES_36, v >> 12)
+        .map(|(r, n)| (r, n, v & !0xfff))
+        .or_else(|| {
+            find(&KEYS_28, &VALUES_28, (v >> 20) as u32).map(|(r, n)| (r, n, v & !0xf_ffff))
+        })
+        .or_else(|| {
+            find(&KEYS_24, &VALUES_24, (v >> 24) as u32).map(|(r, n)| (r, n, v & !0xff_ffff))
+        });
+    hit.map(|(registry, name, network)| Organization::new(name, registry, network))
+}
@@ -0,0 +1,63 @@
+//! The [`Registry`] enum, shared with the build script.
+//!
+//! This file is compiled both as part of the library and, via `#[path]`, by
+//! `build.rs`, so the generated tables and runtime loaders agree on registry
+//! names. It must not refer to anything else in the crate.
+
+/// IEEE registry an assignment was made from.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Registry {
+    /// MAC Address Block Large (24-bit OUI).
+    MaL,
+    /// MAC Address Block Medium (28-bit prefix).
+    MaM,
+    /// MAC Address Block Small (36-bit prefix).
+    MaS,
+    /// Company ID (24-bit, locally administered range).
+    Cid,
+    /// Individual Address Block (36-bit prefix, superseded by MA-S).
+    Iab,
+}
+
+impl Registry {
+    /// Returns the registry name as used by the IEEE (`"MA-L"`, `"CID"`, ...).
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Registry::MaL => "MA-L",
+            Registry::MaM => "MA-M",
+            Registry::MaS => "MA-S",
+            Registry::Cid => "CID",
+            Registry::Iab => "IAB",
+        }
+    }
+
+    /// Parses an IEEE registry name (`"MA-L"`, `"MA-M"`, `"MA-S"`, `"CID"` or `"IAB"`).
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Registry> {
+        match name.trim() {
+            "MA-L" => Some(Registry::MaL),
+            "MA-M" => Some(Registry::MaM),
+            "MA-S" => Some(Registry::MaS),
+            "CID" => Some(Registry::Cid),
+            "IAB" => Some(Registry::Iab),
+            _ => None,
+        }
+    }
+
+    /// Returns the length in bits of prefixes assigned from this registry.
+    #[inline]
+    pub const fn prefix_len(&self) -> u8 {
+        match self {
+            Registry::MaL | Registry::Cid => 24,
+            Registry::MaM => 28,
+            Registry::MaS | Registry::Iab => 36,
+        }
+    }
+
+    /// Returns the number of EUI-48 addresses in one assignment.
+    #[inline]
+    pub const fn block_size(&self) -> u64 {
+        1 << (48 - self.prefix_len() as u32)
+    }
+}
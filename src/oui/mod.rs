@@ -0,0 +1,64 @@
+//! IEEE Registration Authority assignments (OUI, MA-M, MA-S, CID, IAB).
+
+use crate::addr::MacAddr;
+use crate::net::MacNet;
+
+#[cfg(feature = "std")]
+mod csv;
+#[cfg(feature = "oui-db")]
+mod db;
+mod kind;
+#[cfg(feature = "std")]
+mod registry;
+
+pub use kind::Registry;
+#[cfg(feature = "std")]
+pub use registry::OuiRegistry;
+
+/// Organization holding an IEEE assignment, as returned by vendor lookups.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct Organization<'a> {
+    name: &'a str,
+    registry: Registry,
+    prefix: MacNet<MacAddr>,
+}
+
+impl<'a> Organization<'a> {
+    #[cfg_attr(not(any(feature = "oui-db", feature = "std")), allow(dead_code))]
+    #[inline]
+    pub(crate) fn new(name: &'a str, registry: Registry, network: u64) -> Organization<'a> {
+        Organization {
+            name,
+            registry,
+            prefix: MacNet::new(MacAddr::from_u64(network), registry.prefix_len())
+                .expect("registry prefix lengths are at most 48 bits"),
+        }
+    }
+
+    /// Returns the organization name as registered.
+    #[inline]
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns the registry the assignment belongs to.
+    #[inline]
+    pub fn registry(&self) -> Registry {
+        self.registry
+    }
+
+    /// Returns the assigned block, e.g. `00:50:56:00:00:00/24`.
+    #[inline]
+    pub fn prefix(&self) -> MacNet<MacAddr> {
+        self.prefix
+    }
+
+    /// Returns the number of EUI-48 addresses in the assigned block.
+    #[inline]
+    pub fn block_size(&self) -> u64 {
+        self.registry.block_size()
+    }
+}
+
+#[cfg(feature = "oui-db")]
+pub(crate) use db::lookup;
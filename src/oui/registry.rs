@@ -0,0 +1,180 @@
+//! In-memory registry loaded at runtime from IEEE CSV or Wireshark `manuf` files.
+
+use super::csv;
+use super::{Organization, Registry};
+use crate::addr::MacAddr;
+use crate::error::{MalformedLine, RegistryError};
+use std::collections::BTreeMap;
+use std::io::BufRead;
+
+/// Registry of IEEE assignments with longest-prefix lookup.
+///
+/// ```
+/// use mac_addr::{MacAddr, OuiRegistry};
+///
+/// let csv = "Registry,Assignment,Organization Name,Organization Address\n\
+///            MA-L,005056,\"VMware, Inc.\",Palo Alto US\n";
+/// let mut registry = OuiRegistry::new();
+/// registry.load_ieee_csv(csv.as_bytes()).unwrap();
+///
+/// let mac: MacAddr = "00:50:56:c0:00:08".parse().unwrap();
+/// assert_eq!(registry.lookup(mac).unwrap().name(), "VMware, Inc.");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct OuiRegistry {
+    // Keyed by the top 24, 28 and 36 bits respectively.
+    blocks: [BTreeMap<u64, (Registry, String)>; 3],
+}
+
+const PREFIX_LENS: [u8; 3] = [24, 28, 36];
+
+#[inline]
+fn slot(prefix_len: u8) -> Option<usize> {
+    PREFIX_LENS.iter().position(|&l| l == prefix_len)
+}
+
+impl OuiRegistry {
+    /// Creates an empty registry.
+    #[inline]
+    pub fn new() -> OuiRegistry {
+        OuiRegistry::default()
+    }
+
+    /// Returns the number of assignments in the registry.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.blocks.iter().map(BTreeMap::len).sum()
+    }
+
+    /// Returns `true` if the registry holds no assignments.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds or replaces an assignment. `network` is the first address of the block.
+    pub fn insert(&mut self, registry: Registry, network: MacAddr, name: impl Into<String>) {
+        let len = registry.prefix_len();
+        let key = network.to_u64() >> (48 - len as u32);
+        if let Some(i) = slot(len) {
+            self.blocks[i].insert(key, (registry, name.into()));
+        }
+    }
+
+    /// Returns the organization holding the most specific assignment covering `mac`.
+    pub fn lookup(&self, mac: MacAddr) -> Option<Organization<'_>> {
+        let v = mac.to_u64();
+        (0..PREFIX_LENS.len()).rev().find_map(|i| {
+            let host_bits = 48 - PREFIX_LENS[i] as u32;
+            self.blocks[i]
+                .get(&(v >> host_bits))
+                .map(|(registry, name)| {
+                    Organization::new(name, *registry, (v >> host_bits) << host_bits)
+                })
+        })
+    }
+
+    /// Loads an IEEE registry download (`oui.csv`, `mam.csv`, `oui36.csv`, `cid.csv` or `iab.csv`).
+    ///
+    /// A leading header line is skipped. Returns the number of assignments read.
+    pub fn load_ieee_csv<R: BufRead>(&mut self, reader: R) -> Result<usize, RegistryError> {
+        let mut count = 0;
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let malformed = |kind| RegistryError::Malformed { line: i + 1, kind };
+            if line.trim().is_empty() || (i == 0 && line.starts_with("Registry,")) {
+                continue;
+            }
+            let fields =
+                csv::split_fields(&line).ok_or(malformed(MalformedLine::UnterminatedQuote))?;
+            if fields.len() < 3 {
+                return Err(malformed(MalformedLine::MissingField));
+            }
+            let registry = Registry::from_name(&csv::unquote(fields[0]))
+                .ok_or(malformed(MalformedLine::UnknownRegistry))?;
+            let (network, bits) = csv::parse_assignment(&csv::unquote(fields[1]))
+                .ok_or(malformed(MalformedLine::InvalidPrefix))?;
+            if bits != registry.prefix_len() {
+                return Err(malformed(MalformedLine::UnsupportedPrefixLength));
+            }
+            self.insert(
+                registry,
+                MacAddr::from_u64(network),
+                csv::unquote(fields[2]),
+            );
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Loads a Wireshark `manuf` file.
+    ///
+    /// Each line holds a prefix (`00:00:0C` or `00:1B:C5:00:00:00/36`), a short
+    /// name and an optional long name, separated by tabs. The long name is
+    /// preferred when present. `/24`, `/28` and `/36` prefixes are recorded as
+    /// MA-L, MA-M and MA-S respectively. Returns the number of assignments read.
+    pub fn load_manuf<R: BufRead>(&mut self, reader: R) -> Result<usize, RegistryError> {
+        let mut count = 0;
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let malformed = |kind| RegistryError::Malformed { line: i + 1, kind };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split('\t').map(str::trim).filter(|f| !f.is_empty());
+            let prefix = fields
+                .next()
+                .ok_or(malformed(MalformedLine::MissingField))?;
+            let short = fields
+                .next()
+                .ok_or(malformed(MalformedLine::MissingField))?;
+            // Older files carry the long name as a trailing `# comment`.
+            let (short, comment) = match short.split_once('#') {
+                Some((s, c)) => (s.trim(), Some(c.trim())),
+                None => (short, None),
+            };
+            let long = fields
+                .next()
+                .map(|f| f.trim_start_matches('#').trim())
+                .or(comment);
+            let name = long.filter(|l| !l.is_empty()).unwrap_or(short);
+
+            let (network, bits) =
+                parse_manuf_prefix(prefix).ok_or(malformed(MalformedLine::InvalidPrefix))?;
+            let registry = match bits {
+                24 => Registry::MaL,
+                28 => Registry::MaM,
+                36 => Registry::MaS,
+                _ => return Err(malformed(MalformedLine::UnsupportedPrefixLength)),
+            };
+            self.insert(registry, MacAddr::from_u64(network), name);
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+/// Parses `00:00:0C`, `00-00-0C` or `00:1B:C5:00:00:00/36` into a 48-bit network and prefix length.
+fn parse_manuf_prefix(s: &str) -> Option<(u64, u8)> {
+    let (addr, len) = match s.split_once('/') {
+        Some((addr, len)) => (addr, Some(len.parse::<u8>().ok()?)),
+        None => (s, None),
+    };
+    let mut value: u64 = 0;
+    let mut octets = 0;
+    for part in addr.split([':', '-', '.']) {
+        if octets == 6 || part.len() != 2 {
+            return None;
+        }
+        value = (value << 8) | u8::from_str_radix(part, 16).ok()? as u64;
+        octets += 1;
+    }
+    let bits = len.unwrap_or(octets * 8);
+    if bits == 0 || bits > 48 {
+        return None;
+    }
+    let network = value << (48 - octets as u32 * 8);
+    let mask = (!0u64 << (48 - bits as u32)) & 0xffff_ffff_ffff;
+    Some((network & mask, bits))
+}
@@ -0,0 +1,203 @@
+use crate::error::{ParseErrorKind, ParseMacAddrError};
+
+/// Textual notation detected while parsing a MAC address.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum MacNotation {
+    /// Colon-separated octets (`00:25:96:12:34:56`), IEEE/Linux style.
+    Colon,
+    /// Hyphen-separated octets (`00-25-96-12-34-56`), IEEE/Windows style.
+    Hyphen,
+    /// Space-separated octets (`00 25 96 12 34 56`).
+    Space,
+    /// Dot-separated groups of four hex digits (`0025.9612.3456`), Cisco style.
+    Dotted,
+    /// Hex digits without any separator (`002596123456`).
+    Bare,
+}
+
+impl MacNotation {
+    /// Returns the separator character, or `None` for [`MacNotation::Bare`].
+    #[inline]
+    pub fn separator(&self) -> Option<char> {
+        match self {
+            MacNotation::Colon => Some(':'),
+            MacNotation::Hyphen => Some('-'),
+            MacNotation::Space => Some(' '),
+            MacNotation::Dotted => Some('.'),
+            MacNotation::Bare => None,
+        }
+    }
+}
+
+/// How forgiving MAC address parsing should be.
+///
+/// | Input                  | `Strict` | `Standard` | `Lenient` |
+/// |------------------------|----------|------------|-----------|
+/// | `00:25:96:12:34:56`    | ✅       | ✅         | ✅        |
+/// | `0:25:96:12:34:56`     | ⬜       | ✅         | ✅        |
+/// | `00:25-96:12:34:56`    | ⬜       | ⬜         | ✅        |
+/// | ` 00:25:96:12:34:56 `  | ⬜       | ⬜         | ✅        |
+///
+/// [`core::str::FromStr`] uses [`ParseMode::Standard`].
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+pub enum ParseMode {
+    /// Two hex digits per octet, one separator style and no surrounding whitespace.
+    Strict,
+    /// Like `Strict`, but octets may be written with a single hex digit.
+    #[default]
+    Standard,
+    /// Also accepts mixed `:`/`-`/` ` separators and surrounding whitespace.
+    Lenient,
+}
+
+#[inline]
+const fn hex_value(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[inline]
+const fn is_separator(b: u8) -> bool {
+    matches!(b, b':' | b'-' | b' ' | b'.')
+}
+
+#[inline]
+const fn is_octet_separator(b: u8) -> bool {
+    matches!(b, b':' | b'-' | b' ')
+}
+
+/// Parses `s` into `N` octets, accepting any notation in [`MacNotation`].
+///
+/// The separator is taken from the first non-hex character. Unless `mode` is
+/// [`ParseMode::Lenient`], it must be used consistently throughout the input.
+pub(crate) const fn parse_octets<const N: usize>(
+    s: &str,
+    mode: ParseMode,
+) -> Result<([u8; N], MacNotation), ParseMacAddrError> {
+    let lenient = matches!(mode, ParseMode::Lenient);
+    let bytes = s.as_bytes();
+    let mut out = [0u8; N];
+
+    // Work on the trimmed range but report offsets into the original input.
+    let mut begin = 0;
+    let mut end = bytes.len();
+    if lenient {
+        while begin < end && bytes[begin].is_ascii_whitespace() {
+            begin += 1;
+        }
+        while end > begin && bytes[end - 1].is_ascii_whitespace() {
+            end -= 1;
+        }
+    } else if !bytes.is_empty() && bytes[0].is_ascii_whitespace() {
+        return err(ParseErrorKind::UnexpectedWhitespace, 0, 0);
+    } else if !bytes.is_empty() && bytes[end - 1].is_ascii_whitespace() {
+        return err(ParseErrorKind::UnexpectedWhitespace, end - 1, 0);
+    }
+
+    let mut first_sep = begin;
+    while first_sep < end && hex_value(bytes[first_sep]).is_some() {
+        first_sep += 1;
+    }
+
+    if first_sep == end {
+        let digits = end - begin;
+        if digits == 0 {
+            return err(ParseErrorKind::InvalidComponent, begin, 0);
+        }
+        if digits < N * 2 {
+            return err(ParseErrorKind::TooFewComponents, end, digits / 2);
+        }
+        if digits > N * 2 {
+            return err(ParseErrorKind::TooManyComponents, begin + N * 2, N);
+        }
+        let mut i = 0;
+        while i < N {
+            if let (Some(hi), Some(lo)) = (
+                hex_value(bytes[begin + i * 2]),
+                hex_value(bytes[begin + i * 2 + 1]),
+            ) {
+                out[i] = (hi << 4) | lo;
+            }
+            i += 1;
+        }
+        return Ok((out, MacNotation::Bare));
+    }
+
+    let sep = bytes[first_sep];
+    let single_digit = if matches!(mode, ParseMode::Strict) {
+        2
+    } else {
+        1
+    };
+    let (notation, digits_min, digits_max, groups) = match sep {
+        b':' => (MacNotation::Colon, single_digit, 2, N),
+        b'-' => (MacNotation::Hyphen, single_digit, 2, N),
+        b' ' => (MacNotation::Space, single_digit, 2, N),
+        b'.' if N.is_multiple_of(2) => (MacNotation::Dotted, 4, 4, N / 2),
+        _ => return err(ParseErrorKind::InvalidComponent, first_sep, 0),
+    };
+    let bytes_per_group = N / groups;
+
+    let mut pos = begin;
+    let mut group = 0;
+    loop {
+        if group == groups {
+            return err(ParseErrorKind::TooManyComponents, pos, group);
+        }
+        let start = pos;
+        let mut value: u16 = 0;
+        while pos < end {
+            match hex_value(bytes[pos]) {
+                Some(v) if pos - start < digits_max => value = (value << 4) | v as u16,
+                Some(_) => return err(ParseErrorKind::InvalidComponent, pos, group),
+                None => break,
+            }
+            pos += 1;
+        }
+        if pos - start < digits_min {
+            return err(ParseErrorKind::InvalidComponent, pos, group);
+        }
+        if bytes_per_group == 2 {
+            out[group * 2] = (value >> 8) as u8;
+            out[group * 2 + 1] = value as u8;
+        } else {
+            out[group] = value as u8;
+        }
+        group += 1;
+
+        if pos == end {
+            break;
+        }
+        let found = bytes[pos];
+        if found != sep {
+            let interchangeable = lenient && is_octet_separator(sep) && is_octet_separator(found);
+            if !interchangeable {
+                let kind = if is_separator(found) {
+                    ParseErrorKind::MixedSeparators
+                } else {
+                    ParseErrorKind::InvalidComponent
+                };
+                return err(kind, pos, group - 1);
+            }
+        }
+        pos += 1;
+    }
+
+    if group < groups {
+        return err(ParseErrorKind::TooFewComponents, end, group);
+    }
+    Ok((out, notation))
+}
+
+#[inline]
+const fn err<const N: usize>(
+    kind: ParseErrorKind,
+    offset: usize,
+    component: usize,
+) -> Result<([u8; N], MacNotation), ParseMacAddrError> {
+    Err(ParseMacAddrError::new(kind, offset, component, N))
+}
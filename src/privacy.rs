@@ -0,0 +1,111 @@
+use crate::addr::MacAddr;
+use crate::slap::SlapQuadrant;
+
+/// Virtualization platform recognized by [`MacAddr::privacy_class`].
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Hypervisor {
+    /// VMware ESXi, Workstation and Fusion.
+    VMware,
+    /// QEMU/KVM and libvirt.
+    Qemu,
+    /// Microsoft Hyper-V.
+    HyperV,
+    /// Xen.
+    Xen,
+    /// Oracle VirtualBox.
+    VirtualBox,
+    /// Parallels Desktop.
+    Parallels,
+}
+
+impl Hypervisor {
+    /// Returns the product name.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Hypervisor::VMware => "VMware",
+            Hypervisor::Qemu => "QEMU/KVM",
+            Hypervisor::HyperV => "Hyper-V",
+            Hypervisor::Xen => "Xen",
+            Hypervisor::VirtualBox => "VirtualBox",
+            Hypervisor::Parallels => "Parallels",
+        }
+    }
+}
+
+/// Prefixes that hypervisors assign to virtual NICs by default.
+const HYPERVISOR_PREFIXES: [([u8; 3], Hypervisor); 10] = [
+    ([0x00, 0x50, 0x56], Hypervisor::VMware),
+    ([0x00, 0x0c, 0x29], Hypervisor::VMware),
+    ([0x00, 0x05, 0x69], Hypervisor::VMware),
+    ([0x00, 0x1c, 0x14], Hypervisor::VMware),
+    ([0x52, 0x54, 0x00], Hypervisor::Qemu),
+    ([0x00, 0x15, 0x5d], Hypervisor::HyperV),
+    ([0x00, 0x16, 0x3e], Hypervisor::Xen),
+    ([0x08, 0x00, 0x27], Hypervisor::VirtualBox),
+    ([0x0a, 0x00, 0x27], Hypervisor::VirtualBox),
+    ([0x00, 0x1c, 0x42], Hypervisor::Parallels),
+];
+
+/// Prefix Docker assigns to container interfaces (`02:42`).
+const DOCKER_PREFIX: [u8; 2] = [0x02, 0x42];
+
+/// Likely origin of an address, as estimated by [`MacAddr::privacy_class`].
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum PrivacyClass {
+    /// Locally administered unicast address with no known structure, typically a
+    /// per-network randomized address from a phone or laptop.
+    Randomized,
+    /// Default prefix of a virtual NIC.
+    VirtualMachine(Hypervisor),
+    /// Default prefix of a container interface.
+    Container,
+    /// Universally administered address outside the known virtualization prefixes.
+    Universal,
+    /// Locally administered address that is structured or not a device address:
+    /// an ELI (CID-based) address or a group address.
+    UnknownLocal,
+}
+
+impl MacAddr {
+    /// Estimates whether this address is randomized, virtual, or a burned-in hardware address.
+    ///
+    /// This is a heuristic. Hypervisor and container prefixes are checked first.
+    /// Remaining locally administered unicast addresses are reported as
+    /// [`Randomized`](PrivacyClass::Randomized), except those in the IEEE 802c
+    /// ELI quadrant, which are assigned under a Company ID. Randomizing
+    /// clients ignore SLAP, so about a quarter of their addresses fall in the
+    /// ELI quadrant and are reported as [`UnknownLocal`](PrivacyClass::UnknownLocal).
+    ///
+    /// ```
+    /// use mac_addr::{Hypervisor, MacAddr, PrivacyClass};
+    ///
+    /// let vm = MacAddr::new(0x52, 0x54, 0x00, 0x12, 0x34, 0x56);
+    /// assert_eq!(vm.privacy_class(), PrivacyClass::VirtualMachine(Hypervisor::Qemu));
+    /// let phone = MacAddr::new(0xd6, 0x1f, 0x3a, 0x92, 0x04, 0x7c);
+    /// assert_eq!(phone.privacy_class(), PrivacyClass::Randomized);
+    /// ```
+    pub const fn privacy_class(&self) -> PrivacyClass {
+        let oui = self.oui();
+        let mut i = 0;
+        while i < HYPERVISOR_PREFIXES.len() {
+            let (prefix, hypervisor) = HYPERVISOR_PREFIXES[i];
+            if oui[0] == prefix[0] && oui[1] == prefix[1] && oui[2] == prefix[2] {
+                return PrivacyClass::VirtualMachine(hypervisor);
+            }
+            i += 1;
+        }
+        if oui[0] == DOCKER_PREFIX[0] && oui[1] == DOCKER_PREFIX[1] {
+            return PrivacyClass::Container;
+        }
+        if !self.is_locally_administered() {
+            return PrivacyClass::Universal;
+        }
+        if self.is_multicast() {
+            return PrivacyClass::UnknownLocal;
+        }
+        match self.slap_quadrant() {
+            Some(SlapQuadrant::Eli) => PrivacyClass::UnknownLocal,
+            _ => PrivacyClass::Randomized,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::addr::MacAddr;
+use crate::addr8::MacAddr8;
+use rand_core::RngCore;
+
+/// I/G (multicast) bit of the first octet.
+const GROUP_BIT: u8 = 0x01;
+/// U/L (locally administered) bit of the first octet.
+const LOCAL_BIT: u8 = 0x02;
+
+/// Randomizes every bit of `octets` after the first `prefix_len` bits.
+///
+/// If the I/G or U/L bit falls in the randomized part, the result is made
+/// unicast and locally administered respectively.
+pub(crate) fn randomize_suffix<R: RngCore + ?Sized>(
+    octets: &mut [u8],
+    prefix_len: u32,
+    rng: &mut R,
+) {
+    let mut random = [0u8; 8];
+    rng.fill_bytes(&mut random[..octets.len()]);
+    for (i, (o, r)) in octets.iter_mut().zip(random).enumerate() {
+        let start = i as u32 * 8;
+        let keep = match prefix_len.saturating_sub(start) {
+            n if n >= 8 => 0xff,
+            n => !(0xffu8 >> n),
+        };
+        *o = (*o & keep) | (r & !keep);
+    }
+    if prefix_len < 8 {
+        octets[0] &= !GROUP_BIT;
+    }
+    if prefix_len < 7 {
+        octets[0] |= LOCAL_BIT;
+    }
+}
+
+impl MacAddr {
+    /// Generates a random locally administered unicast address (`x2`, `x6`, `xA` or `xE` first octet).
+    #[inline]
+    pub fn random_local_unicast<R: RngCore + ?Sized>(rng: &mut R) -> MacAddr {
+        let mut o = [0u8; 6];
+        randomize_suffix(&mut o, 0, rng);
+        MacAddr::from_octets(o)
+    }
+
+    /// Generates a random unicast address under `oui`.
+    ///
+    /// The I/G bit of `oui` is cleared; the U/L bit is kept as given.
+    #[inline]
+    pub fn random_with_oui<R: RngCore + ?Sized>(oui: [u8; 3], rng: &mut R) -> MacAddr {
+        let mut o = [oui[0] & !GROUP_BIT, oui[1], oui[2], 0, 0, 0];
+        randomize_suffix(&mut o, 24, rng);
+        MacAddr::from_octets(o)
+    }
+
+    /// Generates a random address sharing the first `prefix_len` bits with `value`.
+    ///
+    /// Returns `None` if `prefix_len > 48`. When the prefix is too short to
+    /// cover the I/G or U/L bit, the result is unicast and locally administered.
+    #[inline]
+    pub fn random_in_prefix<R: RngCore + ?Sized>(
+        value: MacAddr,
+        prefix_len: u8,
+        rng: &mut R,
+    ) -> Option<MacAddr> {
+        if prefix_len > 48 {
+            return None;
+        }
+        let mut o = value.octets();
+        randomize_suffix(&mut o, prefix_len as u32, rng);
+        Some(MacAddr::from_octets(o))
+    }
+}
+
+impl MacAddr8 {
+    /// Generates a random locally administered unicast EUI-64.
+    #[inline]
+    pub fn random_local_unicast<R: RngCore + ?Sized>(rng: &mut R) -> MacAddr8 {
+        let mut o = [0u8; 8];
+        randomize_suffix(&mut o, 0, rng);
+        MacAddr8::from_octets(o)
+    }
+
+    /// Generates a random unicast EUI-64 under `oui`.
+    ///
+    /// The I/G bit of `oui` is cleared; the U/L bit is kept as given.
+    #[inline]
+    pub fn random_with_oui<R: RngCore + ?Sized>(oui: [u8; 3], rng: &mut R) -> MacAddr8 {
+        let mut o = [oui[0] & !GROUP_BIT, oui[1], oui[2], 0, 0, 0, 0, 0];
+        randomize_suffix(&mut o, 24, rng);
+        MacAddr8::from_octets(o)
+    }
+
+    /// Generates a random EUI-64 sharing the first `prefix_len` bits with `value`.
+    ///
+    /// Returns `None` if `prefix_len > 64`. When the prefix is too short to
+    /// cover the I/G or U/L bit, the result is unicast and locally administered.
+    #[inline]
+    pub fn random_in_prefix<R: RngCore + ?Sized>(
+        value: MacAddr8,
+        prefix_len: u8,
+        rng: &mut R,
+    ) -> Option<MacAddr8> {
+        if prefix_len > 64 {
+            return None;
+        }
+        let mut o = value.octets();
+        randomize_suffix(&mut o, prefix_len as u32, rng);
+        Some(MacAddr8::from_octets(o))
+    }
+}
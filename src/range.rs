@@ -0,0 +1,226 @@
+use crate::int::MacInteger;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+
+/// Inclusive range of MAC addresses, `start..=end`.
+///
+/// `MacRange` is itself an iterator over its addresses, in the same way as
+/// [`core::ops::RangeInclusive`]. A range with `start > end` is empty.
+///
+/// ```
+/// use mac_addr::{MacAddr, MacRange};
+///
+/// let start = MacAddr::new(0x02, 0, 0, 0, 0, 0x10);
+/// let end = MacAddr::new(0x02, 0, 0, 0, 0, 0x1f);
+/// let pool = MacRange::new(start, end);
+/// assert_eq!(pool.num_addresses(), 16);
+/// assert_eq!(pool.chunks(4).count(), 4);
+/// ```
+///
+/// Equality and hashing consider only the bounds, not whether iteration has
+/// run past the last address.
+#[derive(Clone, Copy, Debug)]
+pub struct MacRange<A> {
+    start: A,
+    end: A,
+    exhausted: bool,
+}
+
+impl<A: PartialEq> PartialEq for MacRange<A> {
+    #[inline]
+    fn eq(&self, other: &MacRange<A>) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl<A: Eq> Eq for MacRange<A> {}
+
+impl<A: Hash> Hash for MacRange<A> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+    }
+}
+
+impl<A: MacInteger> MacRange<A> {
+    /// Constructs the range `start..=end`.
+    #[inline]
+    pub fn new(start: A, end: A) -> MacRange<A> {
+        MacRange {
+            start,
+            end,
+            exhausted: false,
+        }
+    }
+
+    /// Returns the lower bound of the range.
+    ///
+    /// After iteration has started this is the next address to be yielded from the front.
+    #[inline]
+    pub fn start(&self) -> A {
+        self.start
+    }
+
+    /// Returns the upper (inclusive) bound of the range.
+    #[inline]
+    pub fn end(&self) -> A {
+        self.end
+    }
+
+    /// Returns `true` if the range contains no addresses.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.exhausted || self.start > self.end
+    }
+
+    /// Returns the number of addresses in the range.
+    ///
+    /// This is a `u128` because a full EUI-64 range holds 2<sup>64</sup> addresses.
+    #[inline]
+    pub fn num_addresses(&self) -> u128 {
+        if self.is_empty() {
+            0
+        } else {
+            (self.end.to_u64() - self.start.to_u64()) as u128 + 1
+        }
+    }
+
+    /// Returns `true` if `addr` lies within the range.
+    #[inline]
+    pub fn contains(&self, addr: A) -> bool {
+        !self.is_empty() && self.start <= addr && addr <= self.end
+    }
+
+    /// Returns `true` if the two ranges share at least one address.
+    #[inline]
+    pub fn overlaps(&self, other: &MacRange<A>) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the addresses common to both ranges, or `None` if they are disjoint.
+    pub fn intersection(&self, other: &MacRange<A>) -> Option<MacRange<A>> {
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        if start <= end {
+            Some(MacRange::new(start, end))
+        } else {
+            None
+        }
+    }
+
+    /// Splits the range into `start..=at - 1` and `at..=end`.
+    ///
+    /// Returns `None` unless `at` is in the range and greater than `start`,
+    /// so that both halves are non-empty.
+    pub fn split_at(&self, at: A) -> Option<(MacRange<A>, MacRange<A>)> {
+        if !self.contains(at) || at == self.start {
+            return None;
+        }
+        let before = A::from_u64(at.to_u64() - 1);
+        Some((
+            MacRange::new(self.start, before),
+            MacRange::new(at, self.end),
+        ))
+    }
+
+    /// Returns an iterator over consecutive sub-ranges of at most `size` addresses.
+    ///
+    /// The last chunk may be shorter.
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    #[inline]
+    pub fn chunks(&self, size: u64) -> RangeChunks<A> {
+        assert!(size != 0, "chunk size must be non-zero");
+        RangeChunks {
+            remaining: *self,
+            size,
+        }
+    }
+}
+
+impl<A: MacInteger> Iterator for MacRange<A> {
+    type Item = A;
+
+    fn next(&mut self) -> Option<A> {
+        if self.is_empty() {
+            return None;
+        }
+        let current = self.start;
+        if self.start == self.end {
+            self.exhausted = true;
+        } else {
+            self.start = A::from_u64(self.start.to_u64() + 1);
+        }
+        Some(current)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.num_addresses()) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+
+    /// # Panics
+    /// Panics if the number of addresses does not fit in `usize`.
+    #[inline]
+    fn count(self) -> usize {
+        usize::try_from(self.num_addresses()).expect("MacRange length overflows usize")
+    }
+}
+
+impl<A: MacInteger> DoubleEndedIterator for MacRange<A> {
+    fn next_back(&mut self) -> Option<A> {
+        if self.is_empty() {
+            return None;
+        }
+        let current = self.end;
+        if self.start == self.end {
+            self.exhausted = true;
+        } else {
+            self.end = A::from_u64(self.end.to_u64() - 1);
+        }
+        Some(current)
+    }
+}
+
+/// Every EUI-48 range fits in a 64-bit `usize`.
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for MacRange<crate::MacAddr> {}
+
+impl<A: MacInteger> FusedIterator for MacRange<A> {}
+
+/// Iterator returned by [`MacRange::chunks`].
+#[derive(Clone, Debug)]
+pub struct RangeChunks<A> {
+    remaining: MacRange<A>,
+    size: u64,
+}
+
+impl<A: MacInteger> Iterator for RangeChunks<A> {
+    type Item = MacRange<A>;
+
+    fn next(&mut self) -> Option<MacRange<A>> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+        let start = self.remaining.start;
+        let last = start.to_u64().saturating_add(self.size - 1);
+        if last >= self.remaining.end.to_u64() {
+            let chunk = MacRange::new(start, self.remaining.end);
+            self.remaining.exhausted = true;
+            Some(chunk)
+        } else {
+            self.remaining.start = A::from_u64(last + 1);
+            Some(MacRange::new(start, A::from_u64(last)))
+        }
+    }
+}
+
+impl<A: MacInteger> FusedIterator for RangeChunks<A> {}
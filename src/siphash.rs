@@ -0,0 +1,140 @@
+//! SipHash-2-4 (Aumasson and Bernstein, 2012) with a 128-bit key and 64-bit output.
+
+/// Derives a 128-bit SipHash key from a caller key of any length.
+///
+/// `k = SipHash(kdk, 0x00 || key) || SipHash(kdk, 0x01 || key)`, each half
+/// little-endian. Each feature passes its own fixed `kdk`, so one secret
+/// yields unrelated keys in different features.
+pub(crate) fn derive_key(kdk: [u8; 16], key: &[u8]) -> [u8; 16] {
+    let mut k = [0u8; 16];
+    for (i, half) in k.chunks_exact_mut(8).enumerate() {
+        let mut h = SipHasher24::new(kdk);
+        h.write(&[i as u8]);
+        h.write(key);
+        half.copy_from_slice(&h.finish().to_le_bytes());
+    }
+    k
+}
+
+/// Streaming SipHash-2-4 state.
+#[derive(Clone, Debug)]
+pub(crate) struct SipHasher24 {
+    v: [u64; 4],
+    tail: u64,
+    ntail: usize,
+    length: usize,
+}
+
+impl SipHasher24 {
+    /// Creates a hasher keyed with `key`, read as two little-endian words.
+    pub(crate) fn new(key: [u8; 16]) -> SipHasher24 {
+        let mut k0 = [0u8; 8];
+        let mut k1 = [0u8; 8];
+        k0.copy_from_slice(&key[..8]);
+        k1.copy_from_slice(&key[8..]);
+        let k0 = u64::from_le_bytes(k0);
+        let k1 = u64::from_le_bytes(k1);
+        SipHasher24 {
+            v: [
+                k0 ^ 0x736f6d6570736575,
+                k1 ^ 0x646f72616e646f6d,
+                k0 ^ 0x6c7967656e657261,
+                k1 ^ 0x7465646279746573,
+            ],
+            tail: 0,
+            ntail: 0,
+            length: 0,
+        }
+    }
+
+    fn round(&mut self) {
+        let v = &mut self.v;
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13) ^ v[0];
+        v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(16) ^ v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(21) ^ v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(17) ^ v[2];
+        v[2] = v[2].rotate_left(32);
+    }
+
+    fn compress(&mut self, m: u64) {
+        self.v[3] ^= m;
+        self.round();
+        self.round();
+        self.v[0] ^= m;
+    }
+
+    /// Feeds `bytes` into the hash.
+    pub(crate) fn write(&mut self, bytes: &[u8]) {
+        self.length = self.length.wrapping_add(bytes.len());
+        for &b in bytes {
+            self.tail |= (b as u64) << (8 * self.ntail);
+            self.ntail += 1;
+            if self.ntail == 8 {
+                self.compress(self.tail);
+                self.tail = 0;
+                self.ntail = 0;
+            }
+        }
+    }
+
+    /// Returns the 64-bit hash of everything written so far.
+    pub(crate) fn finish(mut self) -> u64 {
+        let m = self.tail | ((self.length as u64 & 0xff) << 56);
+        self.compress(m);
+        self.v[2] ^= 0xff;
+        for _ in 0..4 {
+            self.round();
+        }
+        self.v[0] ^ self.v[1] ^ self.v[2] ^ self.v[3]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SipHasher24;
+
+    const KEY: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+    fn sip(msg: &[u8]) -> u64 {
+        let mut h = SipHasher24::new(KEY);
+        h.write(msg);
+        h.finish()
+    }
+
+    /// Vectors from the SipHash paper: key `00..0f`, message `00..len-1`.
+    #[test]
+    fn reference_vectors() {
+        let msg: [u8; 16] = core::array::from_fn(|i| i as u8);
+        let vectors = [
+            (0, 0x726fdb47dd0e0e31),
+            (1, 0x74f839c593dc67fd),
+            (2, 0x0d6c8009d9a94f5a),
+            (3, 0x85676696d7fb7e2d),
+            (4, 0xcf2794e0277187b7),
+            (5, 0x18765564cd99a68d),
+            (6, 0xcbc9466e58fee3ce),
+            (7, 0xab0200f58b01d137),
+            (8, 0x93f5f5799a932462),
+            (15, 0xa129ca6149be45e5),
+        ];
+        for (len, expected) in vectors {
+            assert_eq!(sip(&msg[..len]), expected, "length {}", len);
+        }
+    }
+
+    #[test]
+    fn split_writes_match_one_shot() {
+        let msg: [u8; 15] = core::array::from_fn(|i| i as u8);
+        for split in 0..=msg.len() {
+            let mut h = SipHasher24::new(KEY);
+            h.write(&msg[..split]);
+            h.write(&msg[split..]);
+            assert_eq!(h.finish(), 0xa129ca6149be45e5, "split at {}", split);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::addr::MacAddr;
+use crate::addr8::MacAddr8;
+#[cfg(feature = "rand")]
+use crate::random::randomize_suffix;
+#[cfg(feature = "rand")]
+use rand_core::RngCore;
+
+/// Bits of the first octet that select the SLAP quadrant (Z and Y).
+const QUADRANT_BITS: u8 = 0x0c;
+
+/// IEEE 802c Structured Local Address Plan (SLAP) quadrant of a locally administered address.
+///
+/// The quadrant is encoded in the second hex digit of the first octet,
+/// ignoring the I/G bit.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum SlapQuadrant {
+    /// Extended Local Identifier (`xA`), derived from an IEEE Company ID.
+    Eli,
+    /// Standard Assigned Identifier (`xE`), assigned by a standard protocol.
+    Sai,
+    /// Administratively Assigned Identifier (`x2`), assigned by a local administrator.
+    Aai,
+    /// Reserved for future use by the IEEE (`x6`).
+    Reserved,
+}
+
+impl SlapQuadrant {
+    /// Returns the quadrant of a first octet, or `None` if it is universally administered.
+    #[inline]
+    pub const fn from_first_octet(octet: u8) -> Option<SlapQuadrant> {
+        if octet & 0x02 == 0 {
+            return None;
+        }
+        Some(match octet & QUADRANT_BITS {
+            0x08 => SlapQuadrant::Eli,
+            0x0c => SlapQuadrant::Sai,
+            0x00 => SlapQuadrant::Aai,
+            _ => SlapQuadrant::Reserved,
+        })
+    }
+
+    /// Returns the low nibble of a unicast first octet in this quadrant.
+    #[inline]
+    pub const fn nibble(&self) -> u8 {
+        match self {
+            SlapQuadrant::Eli => 0x0a,
+            SlapQuadrant::Sai => 0x0e,
+            SlapQuadrant::Aai => 0x02,
+            SlapQuadrant::Reserved => 0x06,
+        }
+    }
+}
+
+/// Returns `true` if `cid` is a unicast Company ID, i.e. lies in the ELI quadrant.
+#[inline]
+const fn is_cid(cid: [u8; 3]) -> bool {
+    cid[0] & 0x0f == SlapQuadrant::Eli.nibble()
+}
+
+impl MacAddr {
+    /// Returns the SLAP quadrant, or `None` if the address is universally administered.
+    #[inline]
+    pub const fn slap_quadrant(&self) -> Option<SlapQuadrant> {
+        SlapQuadrant::from_first_octet(self.0)
+    }
+
+    /// Constructs an Extended Local Identifier from a 24-bit Company ID and a 24-bit extension.
+    ///
+    /// Returns `None` unless `cid` is a unicast CID (second hex digit `A`).
+    #[inline]
+    pub const fn eli(cid: [u8; 3], extension: [u8; 3]) -> Option<MacAddr> {
+        if !is_cid(cid) {
+            return None;
+        }
+        Some(MacAddr(
+            cid[0],
+            cid[1],
+            cid[2],
+            extension[0],
+            extension[1],
+            extension[2],
+        ))
+    }
+
+    /// Generates a random unicast address in the AAI quadrant.
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub fn random_aai<R: RngCore + ?Sized>(rng: &mut R) -> MacAddr {
+        random_in_quadrant::<6, R>(SlapQuadrant::Aai, rng).into()
+    }
+
+    /// Generates a random unicast address in the SAI quadrant.
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub fn random_sai<R: RngCore + ?Sized>(rng: &mut R) -> MacAddr {
+        random_in_quadrant::<6, R>(SlapQuadrant::Sai, rng).into()
+    }
+
+    /// Generates a random unicast ELI under `cid`.
+    ///
+    /// Returns `None` unless `cid` is a unicast CID (second hex digit `A`).
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub fn random_eli<R: RngCore + ?Sized>(cid: [u8; 3], rng: &mut R) -> Option<MacAddr> {
+        if !is_cid(cid) {
+            return None;
+        }
+        let mut o = [cid[0], cid[1], cid[2], 0, 0, 0];
+        randomize_suffix(&mut o, 24, rng);
+        Some(MacAddr::from_octets(o))
+    }
+}
+
+impl MacAddr8 {
+    /// Returns the SLAP quadrant, or `None` if the address is universally administered.
+    #[inline]
+    pub const fn slap_quadrant(&self) -> Option<SlapQuadrant> {
+        SlapQuadrant::from_first_octet(self.0)
+    }
+
+    /// Constructs an Extended Local Identifier from a 24-bit Company ID and a 40-bit extension.
+    ///
+    /// Returns `None` unless `cid` is a unicast CID (second hex digit `A`).
+    #[inline]
+    pub const fn eli(cid: [u8; 3], extension: [u8; 5]) -> Option<MacAddr8> {
+        if !is_cid(cid) {
+            return None;
+        }
+        Some(MacAddr8(
+            cid[0],
+            cid[1],
+            cid[2],
+            extension[0],
+            extension[1],
+            extension[2],
+            extension[3],
+            extension[4],
+        ))
+    }
+
+    /// Generates a random unicast EUI-64 in the AAI quadrant.
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub fn random_aai<R: RngCore + ?Sized>(rng: &mut R) -> MacAddr8 {
+        random_in_quadrant::<8, R>(SlapQuadrant::Aai, rng).into()
+    }
+
+    /// Generates a random unicast EUI-64 in the SAI quadrant.
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub fn random_sai<R: RngCore + ?Sized>(rng: &mut R) -> MacAddr8 {
+        random_in_quadrant::<8, R>(SlapQuadrant::Sai, rng).into()
+    }
+
+    /// Generates a random unicast ELI EUI-64 under `cid`.
+    ///
+    /// Returns `None` unless `cid` is a unicast CID (second hex digit `A`).
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub fn random_eli<R: RngCore + ?Sized>(cid: [u8; 3], rng: &mut R) -> Option<MacAddr8> {
+        if !is_cid(cid) {
+            return None;
+        }
+        let mut o = [cid[0], cid[1], cid[2], 0, 0, 0, 0, 0];
+        randomize_suffix(&mut o, 24, rng);
+        Some(MacAddr8::from_octets(o))
+    }
+}
+
+#[cfg(feature = "rand")]
+fn random_in_quadrant<const N: usize, R: RngCore + ?Sized>(
+    quadrant: SlapQuadrant,
+    rng: &mut R,
+) -> [u8; N] {
+    let mut o = [0u8; N];
+    randomize_suffix(&mut o, 0, rng);
+    o[0] = (o[0] & 0xf0) | quadrant.nibble();
+    o
+}
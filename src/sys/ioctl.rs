@@ -0,0 +1,46 @@
+use crate::addr::MacAddr;
+use std::io;
+use std::mem;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+/// Hardware types whose `SIOCGIFHWADDR` result is a 6-octet MAC address.
+const SIX_OCTET_TYPES: [u16; 6] = [
+    libc::ARPHRD_ETHER,
+    libc::ARPHRD_IEEE802,
+    libc::ARPHRD_LOOPBACK,
+    libc::ARPHRD_IEEE80211,
+    libc::ARPHRD_IEEE80211_PRISM,
+    libc::ARPHRD_IEEE80211_RADIOTAP,
+];
+
+pub(super) fn mac_addr(name: &str) -> io::Result<MacAddr> {
+    // SAFETY: socket() has no memory-safety preconditions; the result is checked below.
+    let fd = unsafe { libc::socket(libc::AF_INET, libc::SOCK_DGRAM | libc::SOCK_CLOEXEC, 0) };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // SAFETY: `fd` is a freshly created descriptor owned by nobody else.
+    let socket = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    // SAFETY: ifreq is plain old data; all-zeros is a valid value.
+    let mut req: libc::ifreq = unsafe { mem::zeroed() };
+    // The caller has checked that `name` is shorter than IFNAMSIZ, so it stays NUL-terminated.
+    for (dst, src) in req.ifr_name.iter_mut().zip(name.bytes()) {
+        *dst = src as libc::c_char;
+    }
+    // The request parameter is `c_ulong` on glibc but `c_int` on musl.
+    let request = libc::SIOCGIFHWADDR as _;
+    // SAFETY: SIOCGIFHWADDR reads `ifr_name` and writes `ifr_hwaddr` within `req`.
+    if unsafe { libc::ioctl(socket.as_raw_fd(), request, &mut req) } < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // SAFETY: the kernel filled in the `ifru_hwaddr` member.
+    let hwaddr = unsafe { req.ifr_ifru.ifru_hwaddr };
+    if !SIX_OCTET_TYPES.contains(&hwaddr.sa_family) {
+        return Err(super::invalid_address());
+    }
+    let d = hwaddr.sa_data;
+    Ok(MacAddr::new(
+        d[0] as u8, d[1] as u8, d[2] as u8, d[3] as u8, d[4] as u8, d[5] as u8,
+    ))
+}
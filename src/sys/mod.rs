@@ -0,0 +1,196 @@
+//! Local interface addresses and the neighbor table on Linux.
+//!
+//! Interfaces are enumerated from sysfs (`/sys/class/net`). A single address
+//! can also be read with the `SIOCGIFHWADDR` ioctl, which works where sysfs
+//! is not mounted. Neighbor (ARP/NDP) entries come from `/proc/net/arp` or
+//! rtnetlink.
+
+use crate::addr::MacAddr;
+use std::io;
+
+mod ioctl;
+mod neigh;
+mod sysfs;
+
+pub use neigh::{Neighbor, NeighborState, arp_table, neighbors, parse_proc_net_arp};
+pub use sysfs::interfaces;
+
+/// Operational state of an interface (RFC 2863), as reported by `operstate`.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+pub enum OperState {
+    /// The state could not be determined. Loopback interfaces report this.
+    #[default]
+    Unknown,
+    /// A component such as the hardware is missing.
+    NotPresent,
+    /// The interface is down.
+    Down,
+    /// Down because a lower-layer interface is down.
+    LowerLayerDown,
+    /// In test mode.
+    Testing,
+    /// Waiting for an external event, such as 802.1X authentication.
+    Dormant,
+    /// The interface is up and can pass packets.
+    Up,
+}
+
+impl OperState {
+    /// Parses the kernel's `operstate` string. Unrecognized values map to [`OperState::Unknown`].
+    pub fn from_name(name: &str) -> OperState {
+        match name {
+            "notpresent" => OperState::NotPresent,
+            "down" => OperState::Down,
+            "lowerlayerdown" => OperState::LowerLayerDown,
+            "testing" => OperState::Testing,
+            "dormant" => OperState::Dormant,
+            "up" => OperState::Up,
+            _ => OperState::Unknown,
+        }
+    }
+}
+
+/// How the kernel assigned an interface's current address (`addr_assign_type`).
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum AddrAssignType {
+    /// Burned-in or otherwise permanent address (`NET_ADDR_PERM`).
+    Permanent,
+    /// Randomly generated by the kernel (`NET_ADDR_RANDOM`).
+    Random,
+    /// Copied from another device, such as a bond's first slave (`NET_ADDR_STOLEN`).
+    Stolen,
+    /// Set from user space (`NET_ADDR_SET`).
+    Set,
+}
+
+impl AddrAssignType {
+    /// Maps the kernel's numeric value.
+    pub fn from_raw(value: u8) -> Option<AddrAssignType> {
+        match value {
+            0 => Some(AddrAssignType::Permanent),
+            1 => Some(AddrAssignType::Random),
+            2 => Some(AddrAssignType::Stolen),
+            3 => Some(AddrAssignType::Set),
+            _ => None,
+        }
+    }
+}
+
+/// A local network interface returned by [`interfaces`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Interface {
+    pub(crate) name: String,
+    pub(crate) index: u32,
+    pub(crate) mac_addr: Option<MacAddr>,
+    pub(crate) permanent_mac_addr: Option<MacAddr>,
+    pub(crate) hardware_type: u16,
+    pub(crate) addr_assign_type: Option<AddrAssignType>,
+    pub(crate) operstate: OperState,
+}
+
+impl Interface {
+    /// Returns the interface name, such as `eth0`.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the interface index.
+    #[inline]
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the current hardware address, or `None` if the interface has none
+    /// or it is not 6 octets long (tunnels, InfiniBand).
+    #[inline]
+    pub fn mac_addr(&self) -> Option<MacAddr> {
+        self.mac_addr
+    }
+
+    /// Returns the permanent (burned-in) address, if known.
+    ///
+    /// This is `bonding_slave/perm_hwaddr` for bond members, or the current
+    /// address when [`addr_assign_type`](Interface::addr_assign_type) is
+    /// [`Permanent`](AddrAssignType::Permanent).
+    #[inline]
+    pub fn permanent_mac_addr(&self) -> Option<MacAddr> {
+        self.permanent_mac_addr
+    }
+
+    /// Returns the `ARPHRD_*` hardware type from `<linux/if_arp.h>`, e.g. 1 for Ethernet and 772 for loopback.
+    #[inline]
+    pub fn hardware_type(&self) -> u16 {
+        self.hardware_type
+    }
+
+    /// Returns how the current address was assigned, if the kernel reports it.
+    #[inline]
+    pub fn addr_assign_type(&self) -> Option<AddrAssignType> {
+        self.addr_assign_type
+    }
+
+    /// Returns the operational state.
+    #[inline]
+    pub fn operstate(&self) -> OperState {
+        self.operstate
+    }
+}
+
+/// Where [`for_interface_with`] reads the address from.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Backend {
+    /// `/sys/class/net/<name>/address`.
+    Sysfs,
+    /// The `SIOCGIFHWADDR` ioctl on a datagram socket.
+    Ioctl,
+}
+
+/// Returns the hardware address of the interface `name`.
+///
+/// Reads sysfs and falls back to the ioctl backend if sysfs is unavailable.
+pub fn for_interface(name: &str) -> io::Result<MacAddr> {
+    match for_interface_with(name, Backend::Sysfs) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => for_interface_with(name, Backend::Ioctl),
+        r => r,
+    }
+}
+
+/// Returns the hardware address of the interface `name` using the given backend.
+///
+/// Fails with [`io::ErrorKind::InvalidInput`] for names that are not valid
+/// interface names, and [`io::ErrorKind::InvalidData`] if the interface has no
+/// 6-octet hardware address.
+pub fn for_interface_with(name: &str, backend: Backend) -> io::Result<MacAddr> {
+    validate_name(name)?;
+    match backend {
+        Backend::Sysfs => sysfs::mac_addr(name),
+        Backend::Ioctl => ioctl::mac_addr(name),
+    }
+}
+
+/// Rejects names the kernel would not accept, and any that could escape `/sys/class/net`.
+fn validate_name(name: &str) -> io::Result<()> {
+    let valid = !name.is_empty()
+        && name.len() < libc::IFNAMSIZ
+        && name != "."
+        && name != ".."
+        && !name
+            .bytes()
+            .any(|b| b == b'/' || b == 0 || b.is_ascii_whitespace());
+    if valid {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "invalid interface name",
+        ))
+    }
+}
+
+fn invalid_address() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "interface has no 6-octet hardware address",
+    )
+}
@@ -0,0 +1,359 @@
+use crate::addr::MacAddr;
+use crate::error::ArpParseError;
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::fs;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+const PROC_NET_ARP: &str = "/proc/net/arp";
+
+/// `ATF_*` flags used by `/proc/net/arp`.
+const ATF_COM: u32 = 0x02;
+const ATF_PERM: u32 = 0x04;
+const ATF_PUBL: u32 = 0x08;
+
+/// `NTF_*` neighbor flags.
+const NTF_PROXY: u8 = 0x08;
+const NTF_ROUTER: u8 = 0x80;
+
+/// Netlink message types and attributes from `<linux/netlink.h>` and `<linux/neighbour.h>`.
+const NLMSG_ERROR: u16 = 2;
+const NLMSG_DONE: u16 = 3;
+const RTM_NEWNEIGH: u16 = 28;
+const RTM_GETNEIGH: u16 = 30;
+const NDA_DST: u16 = 1;
+const NDA_LLADDR: u16 = 2;
+const NLMSG_HDRLEN: usize = 16;
+const NDMSG_LEN: usize = 12;
+
+/// Neighbor cache state (`NUD_*` in `<linux/neighbour.h>`).
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum NeighborState {
+    /// Address resolution is in progress.
+    Incomplete,
+    /// Confirmed reachable recently.
+    Reachable,
+    /// Valid but no longer confirmed.
+    Stale,
+    /// Waiting before sending a probe.
+    Delay,
+    /// Being probed.
+    Probe,
+    /// Resolution failed.
+    Failed,
+    /// The device needs no resolution (loopback, point-to-point).
+    NoArp,
+    /// Statically configured.
+    Permanent,
+    /// No state, or a combination the kernel does not normally report.
+    None,
+}
+
+impl NeighborState {
+    /// Maps a `NUD_*` value.
+    pub fn from_nud(state: u16) -> NeighborState {
+        match state {
+            0x01 => NeighborState::Incomplete,
+            0x02 => NeighborState::Reachable,
+            0x04 => NeighborState::Stale,
+            0x08 => NeighborState::Delay,
+            0x10 => NeighborState::Probe,
+            0x20 => NeighborState::Failed,
+            0x40 => NeighborState::NoArp,
+            0x80 => NeighborState::Permanent,
+            _ => NeighborState::None,
+        }
+    }
+}
+
+/// Entry of the kernel neighbor (ARP/NDP) table.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Neighbor {
+    ip: IpAddr,
+    mac_addr: Option<MacAddr>,
+    interface: String,
+    ifindex: Option<u32>,
+    state: NeighborState,
+    flags: u8,
+}
+
+impl Neighbor {
+    /// Returns the protocol address.
+    #[inline]
+    pub fn ip(&self) -> IpAddr {
+        self.ip
+    }
+
+    /// Returns the resolved hardware address, or `None` if unresolved or not 6 octets long.
+    #[inline]
+    pub fn mac_addr(&self) -> Option<MacAddr> {
+        self.mac_addr
+    }
+
+    /// Returns the interface name.
+    #[inline]
+    pub fn interface(&self) -> &str {
+        &self.interface
+    }
+
+    /// Returns the interface index. `/proc/net/arp` does not report it.
+    #[inline]
+    pub fn ifindex(&self) -> Option<u32> {
+        self.ifindex
+    }
+
+    /// Returns the cache state.
+    ///
+    /// `/proc/net/arp` only distinguishes complete, permanent and incomplete
+    /// entries, reported as [`Reachable`](NeighborState::Reachable),
+    /// [`Permanent`](NeighborState::Permanent) and
+    /// [`Incomplete`](NeighborState::Incomplete).
+    #[inline]
+    pub fn state(&self) -> NeighborState {
+        self.state
+    }
+
+    /// Returns the raw `NTF_*` flags. Published `/proc/net/arp` entries carry `NTF_PROXY`.
+    #[inline]
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    /// Returns `true` if the neighbor is an IPv6 router (`NTF_ROUTER`).
+    #[inline]
+    pub fn is_router(&self) -> bool {
+        self.flags & NTF_ROUTER != 0
+    }
+
+    /// Returns `true` if this is a proxy entry (`NTF_PROXY`).
+    #[inline]
+    pub fn is_proxy(&self) -> bool {
+        self.flags & NTF_PROXY != 0
+    }
+}
+
+/// Parses the contents of `/proc/net/arp`.
+///
+/// The header line is skipped. Entries whose hardware address is all zeros
+/// (unresolved) or not a 6-octet address have no [`Neighbor::mac_addr`].
+///
+/// ```
+/// use mac_addr::sys::{NeighborState, parse_proc_net_arp};
+///
+/// let text = "IP address       HW type     Flags       HW address            Mask     Device\n\
+///             192.0.2.1        0x1         0x2         00:25:96:12:34:56     *        eth0\n";
+/// let table = parse_proc_net_arp(text).unwrap();
+/// assert_eq!(table[0].mac_addr(), Some("00:25:96:12:34:56".parse().unwrap()));
+/// assert_eq!(table[0].state(), NeighborState::Reachable);
+/// ```
+pub fn parse_proc_net_arp(text: &str) -> Result<Vec<Neighbor>, ArpParseError> {
+    let mut out = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        if line.trim().is_empty() || (i == 0 && line.starts_with("IP address")) {
+            continue;
+        }
+        let malformed = ArpParseError::new(i + 1);
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [ip, _hw_type, flags, hw_addr, _mask, device] = fields[..] else {
+            return Err(malformed);
+        };
+        let ip: Ipv4Addr = ip.parse().map_err(|_| malformed)?;
+        let flags =
+            u32::from_str_radix(flags.trim_start_matches("0x"), 16).map_err(|_| malformed)?;
+        let mac_addr = hw_addr
+            .parse::<MacAddr>()
+            .ok()
+            .filter(|m| *m != MacAddr::zero());
+        let state = if flags & ATF_PERM != 0 {
+            NeighborState::Permanent
+        } else if flags & ATF_COM != 0 {
+            NeighborState::Reachable
+        } else {
+            NeighborState::Incomplete
+        };
+        out.push(Neighbor {
+            ip: IpAddr::V4(ip),
+            mac_addr,
+            interface: device.to_string(),
+            ifindex: None,
+            state,
+            flags: if flags & ATF_PUBL != 0 { NTF_PROXY } else { 0 },
+        });
+    }
+    Ok(out)
+}
+
+/// Reads the IPv4 ARP table from `/proc/net/arp`.
+pub fn arp_table() -> io::Result<Vec<Neighbor>> {
+    let text = fs::read_to_string(PROC_NET_ARP)?;
+    parse_proc_net_arp(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Dumps the IPv4 and IPv6 neighbor tables over rtnetlink (`RTM_GETNEIGH`).
+pub fn neighbors() -> io::Result<Vec<Neighbor>> {
+    // SAFETY: socket() has no memory-safety preconditions; the result is checked below.
+    let fd = unsafe {
+        libc::socket(
+            libc::AF_NETLINK,
+            libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+            libc::NETLINK_ROUTE,
+        )
+    };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // SAFETY: `fd` is a freshly created descriptor owned by nobody else.
+    let socket = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    const SEQ: u32 = 1;
+    let mut req = [0u8; NLMSG_HDRLEN + NDMSG_LEN];
+    req[0..4].copy_from_slice(&((NLMSG_HDRLEN + NDMSG_LEN) as u32).to_ne_bytes());
+    req[4..6].copy_from_slice(&RTM_GETNEIGH.to_ne_bytes());
+    let flags = (libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16;
+    req[6..8].copy_from_slice(&flags.to_ne_bytes());
+    req[8..12].copy_from_slice(&SEQ.to_ne_bytes());
+    req[NLMSG_HDRLEN] = libc::AF_UNSPEC as u8;
+    // SAFETY: `req` is a valid buffer of the given length.
+    let sent = unsafe { libc::send(socket.as_raw_fd(), req.as_ptr().cast(), req.len(), 0) };
+    if sent < 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let mut names = HashMap::new();
+    let mut out = Vec::new();
+    let mut buf = vec![0u8; 32 * 1024];
+    loop {
+        // SAFETY: `buf` is a valid, writable buffer of the given length.
+        let n = unsafe { libc::recv(socket.as_raw_fd(), buf.as_mut_ptr().cast(), buf.len(), 0) };
+        if n < 0 {
+            let e = io::Error::last_os_error();
+            if e.kind() == io::ErrorKind::Interrupted {
+                continue;
+            }
+            return Err(e);
+        }
+        let mut msgs = &buf[..n as usize];
+        while msgs.len() >= NLMSG_HDRLEN {
+            let len = u32_at(msgs, 0) as usize;
+            if len < NLMSG_HDRLEN || len > msgs.len() {
+                return Err(truncated());
+            }
+            let kind = u16_at(msgs, 4);
+            let seq = u32_at(msgs, 8);
+            let payload = &msgs[NLMSG_HDRLEN..len];
+            msgs = &msgs[align(len).min(msgs.len())..];
+            if seq != SEQ {
+                continue;
+            }
+            match kind {
+                NLMSG_DONE => return Ok(out),
+                NLMSG_ERROR => {
+                    let errno = payload.get(..4).map(|b| i32_at(b, 0)).unwrap_or(0);
+                    if errno != 0 {
+                        return Err(io::Error::from_raw_os_error(-errno));
+                    }
+                }
+                RTM_NEWNEIGH => {
+                    if let Some(neighbor) = parse_ndmsg(payload, &mut names) {
+                        out.push(neighbor);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Parses an `ndmsg` and its attributes. Returns `None` for non-IP entries.
+fn parse_ndmsg(payload: &[u8], names: &mut HashMap<u32, String>) -> Option<Neighbor> {
+    if payload.len() < NDMSG_LEN {
+        return None;
+    }
+    let family = payload[0] as i32;
+    let ifindex = i32_at(payload, 4) as u32;
+    let state = u16_at(payload, 8);
+    let flags = payload[10];
+
+    let mut ip = None;
+    let mut mac_addr = None;
+    let mut attrs = &payload[NDMSG_LEN..];
+    while attrs.len() >= 4 {
+        let len = u16_at(attrs, 0) as usize;
+        if len < 4 || len > attrs.len() {
+            break;
+        }
+        let value = &attrs[4..len];
+        match u16_at(attrs, 2) {
+            NDA_DST => {
+                ip = match (family, value.len()) {
+                    (libc::AF_INET, 4) => {
+                        Some(IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(value).ok()?)))
+                    }
+                    (libc::AF_INET6, 16) => Some(IpAddr::V6(Ipv6Addr::from(
+                        <[u8; 16]>::try_from(value).ok()?,
+                    ))),
+                    _ => None,
+                }
+            }
+            NDA_LLADDR => mac_addr = MacAddr::try_from(value).ok(),
+            _ => {}
+        }
+        attrs = &attrs[align(len).min(attrs.len())..];
+    }
+
+    // Entries without a destination, such as bridge FDB entries, are skipped
+    // before paying for a name lookup.
+    let ip = ip?;
+    let interface = names
+        .entry(ifindex)
+        .or_insert_with(|| interface_name(ifindex))
+        .clone();
+    Some(Neighbor {
+        ip,
+        mac_addr,
+        interface,
+        ifindex: Some(ifindex),
+        state: NeighborState::from_nud(state),
+        flags,
+    })
+}
+
+/// Returns the name of interface `index`, or the index itself if it has gone away.
+fn interface_name(index: u32) -> String {
+    let mut buf = [0 as libc::c_char; libc::IF_NAMESIZE];
+    // SAFETY: `buf` holds IF_NAMESIZE bytes, as if_indextoname requires.
+    let p = unsafe { libc::if_indextoname(index, buf.as_mut_ptr()) };
+    if p.is_null() {
+        return index.to_string();
+    }
+    // SAFETY: on success the buffer holds a NUL-terminated name.
+    unsafe { CStr::from_ptr(buf.as_ptr()) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[inline]
+fn align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+#[inline]
+fn u16_at(b: &[u8], at: usize) -> u16 {
+    u16::from_ne_bytes([b[at], b[at + 1]])
+}
+
+#[inline]
+fn u32_at(b: &[u8], at: usize) -> u32 {
+    u32::from_ne_bytes([b[at], b[at + 1], b[at + 2], b[at + 3]])
+}
+
+#[inline]
+fn i32_at(b: &[u8], at: usize) -> i32 {
+    u32_at(b, at) as i32
+}
+
+fn truncated() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "truncated netlink message")
+}
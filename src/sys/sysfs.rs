@@ -0,0 +1,75 @@
+use super::{AddrAssignType, Interface, OperState};
+use crate::addr::MacAddr;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const SYS_CLASS_NET: &str = "/sys/class/net";
+
+/// Reads a sysfs attribute with the trailing newline removed.
+fn read_attr(dir: &Path, attr: &str) -> io::Result<String> {
+    let mut s = fs::read_to_string(dir.join(attr))?;
+    s.truncate(s.trim_end().len());
+    Ok(s)
+}
+
+pub(super) fn mac_addr(name: &str) -> io::Result<MacAddr> {
+    let dir = Path::new(SYS_CLASS_NET).join(name);
+    read_attr(&dir, "address")?
+        .parse()
+        .map_err(|_| super::invalid_address())
+}
+
+fn read_interface(dir: &Path, name: String) -> io::Result<Interface> {
+    let index = read_attr(dir, "ifindex")?
+        .parse()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid ifindex"))?;
+    let hardware_type = read_attr(dir, "type")?
+        .parse()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid hardware type"))?;
+    // Some attributes fail with EINVAL rather than being absent, so errors are treated as unknown.
+    let mac_addr = read_attr(dir, "address")
+        .ok()
+        .and_then(|s| s.parse::<MacAddr>().ok());
+    let addr_assign_type = read_attr(dir, "addr_assign_type")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .and_then(AddrAssignType::from_raw);
+    let operstate = read_attr(dir, "operstate")
+        .map(|s| OperState::from_name(&s))
+        .unwrap_or_default();
+    let permanent_mac_addr = match read_attr(dir, "bonding_slave/perm_hwaddr") {
+        Ok(s) => s.parse().ok(),
+        Err(_) if addr_assign_type == Some(AddrAssignType::Permanent) => mac_addr,
+        Err(_) => None,
+    };
+    Ok(Interface {
+        name,
+        index,
+        mac_addr,
+        permanent_mac_addr,
+        hardware_type,
+        addr_assign_type,
+        operstate,
+    })
+}
+
+/// Returns every network interface visible in `/sys/class/net`, ordered by index.
+///
+/// Interfaces removed while the directory is being read are skipped.
+pub fn interfaces() -> io::Result<Vec<Interface>> {
+    let mut out = Vec::new();
+    for entry in fs::read_dir(SYS_CLASS_NET)? {
+        let entry = entry?;
+        let Ok(name) = entry.file_name().into_string() else {
+            continue;
+        };
+        match read_interface(&entry.path(), name) {
+            Ok(iface) => out.push(iface),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    out.sort_by_key(|i| i.index);
+    Ok(out)
+}
@@ -0,0 +1,212 @@
+//! Reserved and protocol-assigned addresses.
+//!
+//! [`MacAddr::well_known`] recognizes every address listed here, as well as
+//! the parameterized VRRP, HSRP and multicast mapping blocks.
+
+use crate::addr::MacAddr;
+
+/// IEEE 802.1D bridge group address, used by STP (`01:80:c2:00:00:00`).
+pub const BRIDGE_GROUP: MacAddr = MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x00);
+/// IEEE 802.3 MAC Control (PAUSE) address (`01:80:c2:00:00:01`).
+pub const PAUSE: MacAddr = MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x01);
+/// IEEE 802.3 Slow Protocols address, used by LACP and OAM (`01:80:c2:00:00:02`).
+pub const SLOW_PROTOCOLS: MacAddr = MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x02);
+/// Nearest non-TPMR bridge address, used by 802.1X and LLDP (`01:80:c2:00:00:03`).
+pub const NEAREST_NON_TPMR_BRIDGE: MacAddr = MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x03);
+/// Nearest bridge address, used by LLDP and PTP peer delay (`01:80:c2:00:00:0e`).
+pub const LLDP_NEAREST_BRIDGE: MacAddr = MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e);
+/// IEEE 1588 PTP primary multicast address (`01:1b:19:00:00:00`).
+pub const PTP: MacAddr = MacAddr(0x01, 0x1b, 0x19, 0x00, 0x00, 0x00);
+/// Cisco CDP, VTP, DTP and UDLD address (`01:00:0c:cc:cc:cc`).
+pub const CISCO_CDP_VTP: MacAddr = MacAddr(0x01, 0x00, 0x0c, 0xcc, 0xcc, 0xcc);
+/// Cisco Shared Spanning Tree (PVST+) address (`01:00:0c:cc:cc:cd`).
+pub const CISCO_PVST: MacAddr = MacAddr(0x01, 0x00, 0x0c, 0xcc, 0xcc, 0xcd);
+/// First address of the RFC 7042 unicast documentation block (`00:00:5e:00:53:00/40`).
+pub const DOCUMENTATION: MacAddr = MacAddr(0x00, 0x00, 0x5e, 0x00, 0x53, 0x00);
+/// First address of the RFC 7042 multicast documentation block (`01:00:5e:90:10:00/40`).
+pub const DOCUMENTATION_MULTICAST: MacAddr = MacAddr(0x01, 0x00, 0x5e, 0x90, 0x10, 0x00);
+
+/// Returns the IPv4 VRRP virtual router address for `vrid` (`00:00:5e:00:01:{vrid}`).
+#[inline]
+pub const fn vrrp_ipv4(vrid: u8) -> MacAddr {
+    MacAddr(0x00, 0x00, 0x5e, 0x00, 0x01, vrid)
+}
+
+/// Returns the IPv6 VRRP virtual router address for `vrid` (`00:00:5e:00:02:{vrid}`).
+#[inline]
+pub const fn vrrp_ipv6(vrid: u8) -> MacAddr {
+    MacAddr(0x00, 0x00, 0x5e, 0x00, 0x02, vrid)
+}
+
+/// Returns the HSRP version 1 virtual address for `group` (`00:00:0c:07:ac:{group}`).
+#[inline]
+pub const fn hsrp_v1(group: u8) -> MacAddr {
+    MacAddr(0x00, 0x00, 0x0c, 0x07, 0xac, group)
+}
+
+/// Returns the HSRP version 2 virtual address for `group` (`00:00:0c:9f:f0:00` plus `group`).
+///
+/// Returns `None` if `group > 4095`.
+#[inline]
+pub const fn hsrp_v2(group: u16) -> Option<MacAddr> {
+    if group > 0x0fff {
+        return None;
+    }
+    Some(MacAddr(
+        0x00,
+        0x00,
+        0x0c,
+        0x9f,
+        0xf0 | (group >> 8) as u8,
+        group as u8,
+    ))
+}
+
+/// Returns the HSRP IPv6 virtual address for `group` (`00:05:73:a0:00:00` plus `group`).
+///
+/// Returns `None` if `group > 4095`.
+#[inline]
+pub const fn hsrp_ipv6(group: u16) -> Option<MacAddr> {
+    if group > 0x0fff {
+        return None;
+    }
+    Some(MacAddr(
+        0x00,
+        0x05,
+        0x73,
+        0xa0,
+        (group >> 8) as u8,
+        group as u8,
+    ))
+}
+
+/// Classification returned by [`MacAddr::well_known`].
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum WellKnown {
+    /// `ff:ff:ff:ff:ff:ff`.
+    Broadcast,
+    /// [`BRIDGE_GROUP`].
+    BridgeGroup,
+    /// [`PAUSE`].
+    Pause,
+    /// [`SLOW_PROTOCOLS`].
+    SlowProtocols,
+    /// [`NEAREST_NON_TPMR_BRIDGE`].
+    NearestNonTpmrBridge,
+    /// [`LLDP_NEAREST_BRIDGE`].
+    LldpNearestBridge,
+    /// Another IEEE 802.1Q reserved address in `01:80:c2:00:00:00/44`; holds the last octet.
+    ReservedBridgeGroup(u8),
+    /// [`PTP`].
+    Ptp,
+    /// [`CISCO_CDP_VTP`].
+    CiscoCdpVtp,
+    /// [`CISCO_PVST`].
+    CiscoPvst,
+    /// IPv4 VRRP virtual router address; holds the VRID.
+    VrrpIpv4(u8),
+    /// IPv6 VRRP virtual router address; holds the VRID.
+    VrrpIpv6(u8),
+    /// HSRP version 1 virtual address; holds the group.
+    HsrpV1(u8),
+    /// HSRP version 2 virtual address; holds the group.
+    HsrpV2(u16),
+    /// HSRP IPv6 virtual address; holds the group.
+    HsrpIpv6(u16),
+    /// RFC 7042 unicast documentation address.
+    Documentation,
+    /// RFC 7042 multicast documentation address.
+    DocumentationMulticast,
+    /// IPv4 multicast mapping (`01:00:5e:00:00:00/25`).
+    Ipv4Multicast,
+    /// IPv6 multicast mapping (`33:33:00:00:00:00/16`).
+    Ipv6Multicast,
+}
+
+impl WellKnown {
+    /// Returns a short human-readable description.
+    pub const fn description(&self) -> &'static str {
+        match self {
+            WellKnown::Broadcast => "Broadcast",
+            WellKnown::BridgeGroup => "IEEE 802.1D bridge group (STP)",
+            WellKnown::Pause => "IEEE 802.3 MAC Control (PAUSE)",
+            WellKnown::SlowProtocols => "IEEE 802.3 Slow Protocols (LACP, OAM)",
+            WellKnown::NearestNonTpmrBridge => "Nearest non-TPMR bridge (802.1X, LLDP)",
+            WellKnown::LldpNearestBridge => "Nearest bridge (LLDP, PTP peer delay)",
+            WellKnown::ReservedBridgeGroup(_) => "IEEE 802.1Q reserved bridge group",
+            WellKnown::Ptp => "IEEE 1588 PTP",
+            WellKnown::CiscoCdpVtp => "Cisco CDP/VTP/DTP/UDLD",
+            WellKnown::CiscoPvst => "Cisco Shared Spanning Tree (PVST+)",
+            WellKnown::VrrpIpv4(_) => "VRRP virtual router (IPv4)",
+            WellKnown::VrrpIpv6(_) => "VRRP virtual router (IPv6)",
+            WellKnown::HsrpV1(_) => "HSRP version 1 virtual router",
+            WellKnown::HsrpV2(_) => "HSRP version 2 virtual router",
+            WellKnown::HsrpIpv6(_) => "HSRP virtual router (IPv6)",
+            WellKnown::Documentation => "RFC 7042 documentation (unicast)",
+            WellKnown::DocumentationMulticast => "RFC 7042 documentation (multicast)",
+            WellKnown::Ipv4Multicast => "IPv4 multicast",
+            WellKnown::Ipv6Multicast => "IPv6 multicast",
+        }
+    }
+
+    /// Returns `true` if IEEE 802.1Q bridges must not forward frames sent to this address.
+    ///
+    /// This holds for the reserved block `01:80:c2:00:00:00` to `01:80:c2:00:00:0f`.
+    pub const fn must_not_forward(&self) -> bool {
+        matches!(
+            self,
+            WellKnown::BridgeGroup
+                | WellKnown::Pause
+                | WellKnown::SlowProtocols
+                | WellKnown::NearestNonTpmrBridge
+                | WellKnown::LldpNearestBridge
+                | WellKnown::ReservedBridgeGroup(_)
+        )
+    }
+}
+
+impl MacAddr {
+    /// Classifies reserved and protocol-assigned addresses.
+    ///
+    /// ```
+    /// use mac_addr::{MacAddr, WellKnown, well_known};
+    ///
+    /// let lldp = well_known::LLDP_NEAREST_BRIDGE;
+    /// assert_eq!(lldp.well_known(), Some(WellKnown::LldpNearestBridge));
+    /// assert!(lldp.well_known().unwrap().must_not_forward());
+    /// assert_eq!(well_known::vrrp_ipv4(7).well_known(), Some(WellKnown::VrrpIpv4(7)));
+    /// assert_eq!(MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56).well_known(), None);
+    /// ```
+    pub const fn well_known(&self) -> Option<WellKnown> {
+        let o = self.octets();
+        let known = match o {
+            [0xff, 0xff, 0xff, 0xff, 0xff, 0xff] => WellKnown::Broadcast,
+            [0x01, 0x80, 0xc2, 0x00, 0x00, n] if n < 0x10 => match n {
+                0x00 => WellKnown::BridgeGroup,
+                0x01 => WellKnown::Pause,
+                0x02 => WellKnown::SlowProtocols,
+                0x03 => WellKnown::NearestNonTpmrBridge,
+                0x0e => WellKnown::LldpNearestBridge,
+                n => WellKnown::ReservedBridgeGroup(n),
+            },
+            [0x01, 0x1b, 0x19, 0x00, 0x00, 0x00] => WellKnown::Ptp,
+            [0x01, 0x00, 0x0c, 0xcc, 0xcc, 0xcc] => WellKnown::CiscoCdpVtp,
+            [0x01, 0x00, 0x0c, 0xcc, 0xcc, 0xcd] => WellKnown::CiscoPvst,
+            [0x00, 0x00, 0x5e, 0x00, 0x01, n] => WellKnown::VrrpIpv4(n),
+            [0x00, 0x00, 0x5e, 0x00, 0x02, n] => WellKnown::VrrpIpv6(n),
+            [0x00, 0x00, 0x5e, 0x00, 0x53, _] => WellKnown::Documentation,
+            [0x00, 0x00, 0x0c, 0x07, 0xac, n] => WellKnown::HsrpV1(n),
+            [0x00, 0x00, 0x0c, 0x9f, hi, lo] if hi & 0xf0 == 0xf0 => {
+                WellKnown::HsrpV2(((hi as u16 & 0x0f) << 8) | lo as u16)
+            }
+            [0x00, 0x05, 0x73, 0xa0, hi, lo] if hi & 0xf0 == 0 => {
+                WellKnown::HsrpIpv6(((hi as u16) << 8) | lo as u16)
+            }
+            [0x01, 0x00, 0x5e, 0x90, 0x10, _] => WellKnown::DocumentationMulticast,
+            [0x01, 0x00, 0x5e, n, _, _] if n & 0x80 == 0 => WellKnown::Ipv4Multicast,
+            [0x33, 0x33, _, _, _, _] => WellKnown::Ipv6Multicast,
+            _ => return None,
+        };
+        Some(known)
+    }
+}
@@ -0,0 +1,169 @@
+//! Wake-on-LAN magic packets.
+//!
+//! A magic packet is six `0xff` bytes followed by the target address
+//! repeated 16 times (102 bytes), optionally followed by a 4- or 6-byte
+//! SecureOn password. It is usually sent as a UDP broadcast to port 9.
+
+use crate::addr::MacAddr;
+use crate::error::WolError;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket};
+
+/// Length of a magic packet without a password.
+pub const PACKET_LEN: usize = 102;
+
+/// Conventional UDP port for Wake-on-LAN (discard).
+pub const DEFAULT_PORT: u16 = 9;
+
+const SYNC_LEN: usize = 6;
+const REPETITIONS: usize = 16;
+const MAX_LEN: usize = PACKET_LEN + 6;
+
+/// Wake-on-LAN magic packet.
+///
+/// ```
+/// use mac_addr::MacAddr;
+/// use mac_addr::wol::MagicPacket;
+///
+/// let mac = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+/// let packet = MagicPacket::new(mac);
+/// assert_eq!(packet.as_bytes().len(), 102);
+///
+/// let received = MagicPacket::parse(packet.as_bytes()).unwrap();
+/// assert_eq!(received.mac_addr(), mac);
+/// assert_eq!(received.password(), None);
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct MagicPacket {
+    bytes: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl MagicPacket {
+    /// Builds the packet that wakes `mac`.
+    pub const fn new(mac: MacAddr) -> MagicPacket {
+        let mut bytes = [0xff; MAX_LEN];
+        let octets = mac.octets();
+        let mut i = SYNC_LEN;
+        while i < PACKET_LEN {
+            bytes[i] = octets[(i - SYNC_LEN) % 6];
+            i += 1;
+        }
+        MagicPacket {
+            bytes,
+            len: PACKET_LEN,
+        }
+    }
+
+    /// Builds the packet that wakes `mac` with a 4- or 6-byte SecureOn password.
+    pub fn with_password(mac: MacAddr, password: &[u8]) -> Result<MagicPacket, WolError> {
+        if password.len() != 4 && password.len() != 6 {
+            return Err(WolError::InvalidPasswordLength);
+        }
+        let mut packet = MagicPacket::new(mac);
+        packet.bytes[PACKET_LEN..PACKET_LEN + password.len()].copy_from_slice(password);
+        packet.len = PACKET_LEN + password.len();
+        Ok(packet)
+    }
+
+    /// Parses and validates a received packet.
+    ///
+    /// `bytes` must be exactly the packet: 102 bytes, or 106 or 108 with a password.
+    pub fn parse(bytes: &[u8]) -> Result<MagicPacket, WolError> {
+        if !matches!(bytes.len(), PACKET_LEN | 106 | MAX_LEN) {
+            return Err(WolError::InvalidLength);
+        }
+        if bytes[..SYNC_LEN].iter().any(|&b| b != 0xff) {
+            return Err(WolError::MissingSync);
+        }
+        let target = &bytes[SYNC_LEN..SYNC_LEN + 6];
+        if bytes[SYNC_LEN..PACKET_LEN]
+            .chunks_exact(6)
+            .take(REPETITIONS)
+            .any(|c| c != target)
+        {
+            return Err(WolError::MismatchedRepetition);
+        }
+        let mac = MacAddr::new(
+            target[0], target[1], target[2], target[3], target[4], target[5],
+        );
+        match bytes.len() {
+            PACKET_LEN => Ok(MagicPacket::new(mac)),
+            _ => MagicPacket::with_password(mac, &bytes[PACKET_LEN..]),
+        }
+    }
+
+    /// Finds a magic packet anywhere in `bytes`, such as a UDP payload or a
+    /// raw Ethernet frame with headers and padding around it.
+    ///
+    /// Returns the first sync stream followed by 16 copies of one address.
+    /// A password cannot be told apart from whatever follows the packet, so
+    /// the result never carries one; use [`MagicPacket::parse`] on the exact
+    /// packet bytes when a password is expected.
+    pub fn find(bytes: &[u8]) -> Option<MagicPacket> {
+        bytes
+            .windows(PACKET_LEN)
+            .find_map(|w| MagicPacket::parse(w).ok())
+    }
+
+    /// Returns the target address.
+    #[inline]
+    pub fn mac_addr(&self) -> MacAddr {
+        let b = &self.bytes[SYNC_LEN..];
+        MacAddr::new(b[0], b[1], b[2], b[3], b[4], b[5])
+    }
+
+    /// Returns the SecureOn password, if any.
+    #[inline]
+    pub fn password(&self) -> Option<&[u8]> {
+        match self.len {
+            PACKET_LEN => None,
+            len => Some(&self.bytes[PACKET_LEN..len]),
+        }
+    }
+
+    /// Returns the packet bytes.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Broadcasts the packet to `255.255.255.255` on [`DEFAULT_PORT`].
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn send(&self) -> io::Result<()> {
+        self.send_to(SocketAddr::from((Ipv4Addr::BROADCAST, DEFAULT_PORT)))
+    }
+
+    /// Sends the packet over UDP to `target`, such as a subnet broadcast address.
+    ///
+    /// The socket is bound to an ephemeral port with broadcast enabled.
+    #[cfg(feature = "std")]
+    pub fn send_to(&self, target: SocketAddr) -> io::Result<()> {
+        let local = match target {
+            SocketAddr::V4(_) => SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)),
+            SocketAddr::V6(_) => SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0)),
+        };
+        let socket = UdpSocket::bind(local)?;
+        if target.is_ipv4() {
+            socket.set_broadcast(true)?;
+        }
+        let sent = socket.send_to(self.as_bytes(), target)?;
+        if sent != self.len {
+            return Err(io::Error::new(
+                io::ErrorKind::WriteZero,
+                "magic packet was truncated",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl AsRef<[u8]> for MagicPacket {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
@@ -0,0 +1,80 @@
+// Pseudonymization, permutation and redaction tests.
+
+use mac_addr::{Anonymizer, MacAddr, MacAddr8, MacFormat, Separator};
+use std::collections::HashSet;
+
+const MAC: MacAddr = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+
+#[test]
+fn pseudonyms_are_keyed_and_stable() {
+    let a = Anonymizer::new(b"key-a");
+    let b = Anonymizer::new(b"key-b");
+    let p = a.pseudonymize(MAC);
+    assert_eq!(p, a.pseudonymize(MAC));
+    assert_ne!(p, b.pseudonymize(MAC));
+    assert_ne!(p, MAC);
+    assert!(p.is_locally_administered());
+    assert!(p.is_unicast());
+
+    let group = MacAddr::new(0x01, 0x00, 0x5e, 0x00, 0x00, 0xfb);
+    assert!(a.pseudonymize(group).is_multicast());
+
+    let m8 = MacAddr8::new(0x00, 0x25, 0x96, 1, 2, 3, 4, 5);
+    let p8 = a.pseudonymize(m8);
+    assert!(p8.is_locally_administered() && p8.is_unicast());
+
+    let k = a.pseudonymize_keep_oui(MAC);
+    assert_eq!(k.oui(), MAC.oui());
+    assert_ne!(k, MAC);
+    assert_eq!(a.pseudonymize_keep_oui(m8).oui(), m8.oui());
+}
+
+#[test]
+fn permutation_is_a_bijection() {
+    let anon = Anonymizer::new(b"secret");
+    let mut seen = HashSet::new();
+    for i in 0..4096u64 {
+        let mac = MacAddr::from_u64(0x0025_9612_0000 + i);
+        let p = anon.permute(mac);
+        assert!(seen.insert(p));
+        assert_eq!(anon.unpermute(p), mac);
+
+        let k = anon.permute_keep_oui(mac);
+        assert_eq!(k.oui(), mac.oui());
+        assert_eq!(anon.unpermute_keep_oui(k), mac);
+    }
+
+    let m8 = MacAddr8::from_u64(0xfedc_ba98_7654_3210);
+    assert_eq!(anon.unpermute(anon.permute(m8)), m8);
+    assert_eq!(anon.unpermute_keep_oui(anon.permute_keep_oui(m8)), m8);
+}
+
+#[test]
+fn redaction_formatters() {
+    assert_eq!(MAC.redacted(3).to_string(), "00:25:96:xx:xx:xx");
+    assert_eq!(MAC.redacted(0).to_string(), "xx:xx:xx:xx:xx:xx");
+    assert_eq!(MAC.redacted(6).to_string(), MAC.to_string());
+    assert_eq!(MAC.redacted(10).to_string(), MAC.to_string());
+    let upper = MacFormat::new()
+        .separator(Separator::Hyphen)
+        .uppercase(true);
+    assert_eq!(
+        MAC.redacted(4).format(upper).to_string(),
+        "00-25-96-12-XX-XX"
+    );
+    let m8 = MacAddr8::new(0x00, 0x25, 0x96, 1, 2, 3, 4, 5);
+    assert_eq!(m8.redacted(3).to_string(), "00:25:96:xx:xx:xx:xx:xx");
+    assert_eq!(format!("{:?}", Anonymizer::new(b"k")), "Anonymizer { .. }");
+}
+
+#[test]
+fn redacted_debug_hides_octets() {
+    let debug = format!("{:?}", MAC.redacted(3));
+    assert_eq!(debug, "Redacted(00:25:96:xx:xx:xx)");
+    // Hidden octets 0x12, 0x34, 0x56 in hex or decimal.
+    for hidden in ["12", "34", "56", "18", "52", "86"] {
+        assert!(!debug.contains(hidden), "{debug} leaks {hidden}");
+    }
+    let pretty = format!("{:#?}", MAC.redacted(0));
+    assert!(!pretty.contains("25") && pretty.contains("xx:xx:xx:xx:xx:xx"));
+}
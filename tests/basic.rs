@@ -1,7 +1,7 @@
 // Core API tests for mac-addr without requiring std (works with std or alloc).
 
 use core::str::FromStr;
-use mac_addr::{MacAddr, ParseMacAddrError};
+use mac_addr::{MacAddr, ParseErrorKind};
 
 #[test]
 fn construct_and_octets() {
@@ -63,15 +63,15 @@ fn from_str_ok_lower_and_upper() {
 fn from_str_errors() {
     // Too few components
     let e = MacAddr::from_str("00:11:22").unwrap_err();
-    assert_eq!(e, ParseMacAddrError::TooFewComponents);
+    assert_eq!(e.kind(), ParseErrorKind::TooFewComponents);
 
     // Too many components
     let e = MacAddr::from_str("00:11:22:33:44:55:66").unwrap_err();
-    assert_eq!(e, ParseMacAddrError::TooManyComponents);
+    assert_eq!(e.kind(), ParseErrorKind::TooManyComponents);
 
     // Invalid component (non-hex)
     let e = MacAddr::from_str("00:GG:22:33:44:55").unwrap_err();
-    assert_eq!(e, ParseMacAddrError::InvalidComponent);
+    assert_eq!(e.kind(), ParseErrorKind::InvalidComponent);
 }
 
 #[test]
@@ -112,3 +112,22 @@ fn address_string_when_alloc_available() {
     let m = MacAddr::new(0xde, 0xad, 0xbe, 0xef, 0x00, 0x01);
     assert_eq!(m.address(), "de:ad:be:ef:00:01");
 }
+
+#[test]
+fn const_constructors_and_macro() {
+    const STP: MacAddr = mac_addr::mac!("01:80:c2:00:00:00");
+    const PTP: MacAddr = mac_addr::mac!("011b.1900.0000");
+    const ZERO: MacAddr = MacAddr::zero();
+    const OUI: [u8; 3] = MacAddr::from_octets([0x00, 0x25, 0x96, 1, 2, 3]).oui();
+    const PARSED: Result<MacAddr, mac_addr::ParseMacAddrError> =
+        MacAddr::parse_with_mode("00-25-96-12-34-56", mac_addr::ParseMode::Strict);
+
+    assert!(STP.is_multicast());
+    assert_eq!(PTP.octets(), [0x01, 0x1b, 0x19, 0x00, 0x00, 0x00]);
+    assert_eq!(ZERO, MacAddr::new(0, 0, 0, 0, 0, 0));
+    assert_eq!(OUI, [0x00, 0x25, 0x96]);
+    assert_eq!(
+        PARSED.unwrap().octets(),
+        [0x00, 0x25, 0x96, 0x12, 0x34, 0x56]
+    );
+}
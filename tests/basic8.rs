@@ -1,7 +1,7 @@
 // Core API tests for MacAddr8 (no std required).
 
 use core::str::FromStr;
-use mac_addr::{MacAddr, MacAddr8, ParseMacAddrError};
+use mac_addr::{MacAddr, MacAddr8, ParseErrorKind};
 
 #[test]
 fn construct_and_octets_8() {
@@ -53,15 +53,15 @@ fn from_str_ok_lower_and_upper_8() {
 fn from_str_errors_8() {
     // Too few components
     let e = MacAddr8::from_str("00:11:22:33").unwrap_err();
-    assert_eq!(e, ParseMacAddrError::TooFewComponents);
+    assert_eq!(e.kind(), ParseErrorKind::TooFewComponents);
 
     // Too many components
     let e = MacAddr8::from_str("00:11:22:33:44:55:66:77:88").unwrap_err();
-    assert_eq!(e, ParseMacAddrError::TooManyComponents);
+    assert_eq!(e.kind(), ParseErrorKind::TooManyComponents);
 
     // Invalid component (non-hex)
     let e = MacAddr8::from_str("00:GG:22:33:44:55:66:77").unwrap_err();
-    assert_eq!(e, ParseMacAddrError::InvalidComponent);
+    assert_eq!(e.kind(), ParseErrorKind::InvalidComponent);
 }
 
 #[test]
@@ -99,3 +99,11 @@ fn address_string_when_alloc_available_8() {
     let m = MacAddr8::new(0xde, 0xad, 0xbe, 0xef, 0x00, 0x01, 0x02, 0x03);
     assert_eq!(m.address(), "de:ad:be:ef:00:01:02:03");
 }
+
+#[test]
+fn const_constructors_and_macro_8() {
+    const M8: MacAddr8 = mac_addr::mac8!("00:25:96:ff:fe:12:34:56");
+    const M6: Option<MacAddr> = M8.to_eui48();
+    assert_eq!(M6.unwrap().octets(), [0x00, 0x25, 0x96, 0x12, 0x34, 0x56]);
+    assert_eq!(MacAddr8::from_eui48(M6.unwrap()), M8);
+}
@@ -0,0 +1,45 @@
+// Deterministic random number generators shared by the `rand` tests.
+
+#![allow(dead_code)]
+
+use rand_core::RngCore;
+
+/// Small deterministic xorshift generator for tests.
+pub struct XorShift(pub u64);
+
+impl RngCore for XorShift {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(8) {
+            let v = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&v[..chunk.len()]);
+        }
+    }
+}
+
+/// Generator returning all-ones, to check that flag bits are forced.
+pub struct Ones;
+
+impl RngCore for Ones {
+    fn next_u32(&mut self) -> u32 {
+        u32::MAX
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        u64::MAX
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        dst.fill(0xff);
+    }
+}
@@ -0,0 +1,291 @@
+// Ethernet II / IEEE 802.3 header tests.
+
+use mac_addr::ethernet::{
+    self, EthernetBuilder, EthernetHeader, Llc, LlcControl, Snap, TypeOrLength, VlanTag,
+};
+use mac_addr::{EthernetError, MacAddr};
+
+const DST: MacAddr = MacAddr::new(0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e);
+const SRC: MacAddr = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+
+#[test]
+fn ethernet_ii() {
+    let mut frame = vec![0xff; 6];
+    frame.extend_from_slice(&SRC.octets());
+    frame.extend_from_slice(&[0x86, 0xdd, 0x60, 0x00]);
+    let h = EthernetHeader::parse(&frame).unwrap();
+    assert!(h.destination().is_broadcast());
+    assert_eq!(h.source(), SRC);
+    assert_eq!(h.vlan_count(), 0);
+    assert_eq!(
+        h.type_or_length(),
+        TypeOrLength::EtherType(ethernet::ETHERTYPE_IPV6)
+    );
+    assert_eq!(h.ether_type(), Some(ethernet::ETHERTYPE_IPV6));
+    assert_eq!(h.llc(), None);
+    assert_eq!(h.header_len(), 14);
+    assert_eq!(h.as_bytes(), &frame[..14]);
+    assert_eq!(h.payload(), &[0x60, 0x00]);
+}
+
+#[test]
+fn qinq_stack() {
+    let tags = [
+        VlanTag {
+            tpid: ethernet::ETHERTYPE_QINQ,
+            pcp: 5,
+            dei: true,
+            vid: 100,
+        },
+        VlanTag::customer(4094),
+    ];
+    let b = EthernetBuilder::new(DST, SRC, ethernet::ETHERTYPE_IPV4)
+        .vlan(tags[0])
+        .vlan(tags[1]);
+    let mut buf = [0u8; 30];
+    assert_eq!(b.write(&mut buf), Ok(22));
+    assert_eq!(&buf[12..16], &[0x88, 0xa8, 0xb0, 0x64]);
+    assert_eq!(&buf[16..22], &[0x81, 0x00, 0x0f, 0xfe, 0x08, 0x00]);
+
+    let h = EthernetHeader::parse(&buf).unwrap();
+    assert_eq!(h.destination(), DST);
+    assert_eq!(h.vlan_count(), 2);
+    assert_eq!(h.vlan_tags().collect::<Vec<_>>(), tags);
+    assert_eq!(h.vlan_tags().len(), 2);
+    assert_eq!(h.ether_type(), Some(ethernet::ETHERTYPE_IPV4));
+    assert_eq!(h.payload().len(), 8);
+}
+
+#[test]
+fn vlan_tci() {
+    let tag = VlanTag::from_tci(ethernet::ETHERTYPE_VLAN, 0xe00a);
+    assert_eq!((tag.pcp, tag.dei, tag.vid), (7, false, 10));
+    assert_eq!(tag.tci(), 0xe00a);
+    assert_eq!(VlanTag::service(0x1fff).vid, 0x0fff);
+}
+
+#[test]
+fn ieee8023_llc() {
+    // STP BPDU: 802.3 length with LLC 42 42 03, followed by padding.
+    let stp = Llc {
+        dsap: 0x42,
+        ssap: 0x42,
+        control: LlcControl::Unnumbered(0x03),
+    };
+    let mut frame = [0u8; 60];
+    let len = EthernetBuilder::ieee8023(DST, SRC)
+        .llc(stp, 35)
+        .write(&mut frame)
+        .unwrap();
+    assert_eq!(len, 17);
+    assert_eq!(&frame[12..17], &[0x00, 38, 0x42, 0x42, 0x03]);
+
+    let h = EthernetHeader::parse(&frame).unwrap();
+    assert_eq!(h.type_or_length(), TypeOrLength::Length(38));
+    assert_eq!(h.llc(), Some(stp));
+    assert_eq!(h.snap(), None);
+    assert_eq!(h.ether_type(), None);
+    assert_eq!(h.header_len(), 17);
+    assert_eq!(h.payload().len(), 35);
+
+    // I-format PDUs carry a two-byte control field.
+    let i_pdu = Llc {
+        dsap: 0xf0,
+        ssap: 0xf0,
+        control: LlcControl::Sequenced(0x0102),
+    };
+    let len = EthernetBuilder::ieee8023(DST, SRC)
+        .llc(i_pdu, 0)
+        .write(&mut frame)
+        .unwrap();
+    assert_eq!(len, 18);
+    let h = EthernetHeader::parse(&frame[..len]).unwrap();
+    assert_eq!(h.llc(), Some(i_pdu));
+    assert_eq!(h.payload(), &[]);
+
+    // A second control byte ending in 0b11 must not be mistaken for U-format.
+    let s_pdu = Llc {
+        dsap: 0x06,
+        ssap: 0x06,
+        control: LlcControl::Sequenced(0x0203),
+    };
+    assert_eq!(s_pdu.encoded_len(), 4);
+    let len = EthernetBuilder::ieee8023(DST, SRC)
+        .llc(s_pdu, 4)
+        .write(&mut frame)
+        .unwrap();
+    assert_eq!(len, 18);
+    assert_eq!(&frame[12..18], &[0x00, 0x08, 0x06, 0x06, 0x02, 0x03]);
+
+    let mut raw = [0u8; 22];
+    raw[12..22].copy_from_slice(&[0x00, 0x08, 0x06, 0x06, 0x02, 0x03, 0xaa, 0xbb, 0xcc, 0xdd]);
+    let h = EthernetHeader::parse(&raw).unwrap();
+    assert_eq!(h.llc(), Some(s_pdu));
+    assert_eq!(h.header_len(), 18);
+    assert_eq!(h.payload(), &[0xaa, 0xbb, 0xcc, 0xdd]);
+
+    // `00 03` is an I-format field, not the U-format byte `03`.
+    let i_zero = Llc {
+        control: LlcControl::Sequenced(0x0003),
+        ..s_pdu
+    };
+    assert_eq!(i_zero.encoded_len(), 4);
+    let len = EthernetBuilder::ieee8023(DST, SRC)
+        .llc(i_zero, 0)
+        .write(&mut frame)
+        .unwrap();
+    assert_eq!(
+        EthernetHeader::parse(&frame[..len]).unwrap().llc(),
+        Some(i_zero)
+    );
+
+    // Format bits that contradict the variant are rejected.
+    for control in [LlcControl::Unnumbered(0x02), LlcControl::Sequenced(0x0302)] {
+        let llc = Llc { control, ..s_pdu };
+        assert_eq!(
+            EthernetBuilder::ieee8023(DST, SRC)
+                .llc(llc, 0)
+                .write(&mut frame),
+            Err(EthernetError::InvalidLlcControl)
+        );
+    }
+}
+
+#[test]
+fn ieee8023_snap() {
+    let cdp = Snap {
+        oui: [0x00, 0x00, 0x0c],
+        protocol_id: 0x2000,
+    };
+    let mut frame = [0u8; 64];
+    let len = EthernetBuilder::ieee8023(DST, SRC)
+        .vlan(VlanTag::customer(7))
+        .snap(cdp, 10)
+        .write(&mut frame)
+        .unwrap();
+    assert_eq!(len, 26);
+    let h = EthernetHeader::parse(&frame).unwrap();
+    assert_eq!(h.vlan_count(), 1);
+    assert_eq!(h.type_or_length(), TypeOrLength::Length(18));
+    assert_eq!(h.llc(), Some(Llc::SNAP));
+    assert_eq!(h.snap(), Some(cdp));
+    assert_eq!(h.ether_type(), None);
+    assert_eq!(h.payload().len(), 10);
+
+    // RFC 1042: SNAP with a zero OUI carries an EtherType.
+    let rfc1042 = Snap {
+        oui: [0, 0, 0],
+        protocol_id: ethernet::ETHERTYPE_ARP,
+    };
+    let len = EthernetBuilder::ieee8023(DST, SRC)
+        .snap(rfc1042, 28)
+        .write(&mut frame)
+        .unwrap();
+    let h = EthernetHeader::parse(&frame).unwrap();
+    assert_eq!(h.header_len(), len);
+    assert_eq!(h.ether_type(), Some(ethernet::ETHERTYPE_ARP));
+}
+
+#[test]
+fn errors() {
+    let mut frame = [0u8; 22];
+    frame[12..14].copy_from_slice(&[0x81, 0x00]);
+    assert_eq!(
+        EthernetHeader::parse(&frame[..13]),
+        Err(EthernetError::Truncated)
+    );
+    assert_eq!(
+        EthernetHeader::parse(&frame[..17]),
+        Err(EthernetError::Truncated)
+    );
+    frame[16..18].copy_from_slice(&[0x05, 0xff]);
+    assert_eq!(
+        EthernetHeader::parse(&frame),
+        Err(EthernetError::InvalidTypeOrLength(0x05ff))
+    );
+    // 802.3 length with no room for LLC.
+    frame[16..18].copy_from_slice(&[0x00, 0x03]);
+    assert_eq!(
+        EthernetHeader::parse(&frame[..20]),
+        Err(EthernetError::Truncated)
+    );
+    // SNAP header cut short.
+    frame[18..21].copy_from_slice(&[0xaa, 0xaa, 0x03]);
+    assert_eq!(EthernetHeader::parse(&frame), Err(EthernetError::Truncated));
+
+    let b = EthernetBuilder::new(DST, SRC, ethernet::ETHERTYPE_IPV4);
+    assert_eq!(b.write(&mut [0u8; 13]), Err(EthernetError::BufferTooSmall));
+    let snap = Snap {
+        oui: [0, 0, 0],
+        protocol_id: ethernet::ETHERTYPE_IPV4,
+    };
+    assert_eq!(
+        b.snap(snap, 1493).write(&mut [0u8; 32]),
+        Err(EthernetError::LengthTooLarge)
+    );
+    assert_eq!(b.snap(snap, 1492).write(&mut [0u8; 32]), Ok(22));
+    assert_eq!(
+        b.snap(snap, usize::MAX).write(&mut [0u8; 32]),
+        Err(EthernetError::LengthTooLarge)
+    );
+    // SNAP SAPs without a SNAP body would not parse back.
+    assert_eq!(
+        b.llc(Llc::SNAP, 0).write(&mut [0u8; 32]),
+        Err(EthernetError::MissingSnap)
+    );
+}
+
+#[test]
+fn builder_rejects_what_would_not_parse_back() {
+    let mut b = EthernetBuilder::new(DST, SRC, ethernet::ETHERTYPE_IPV4);
+    for vid in 0..ethernet::MAX_VLAN_TAGS as u16 {
+        b = b.vlan(VlanTag::customer(vid));
+    }
+    assert_eq!(b.write(&mut [0u8; 64]), Ok(30));
+    let b = b.vlan(VlanTag::customer(99));
+    assert_eq!(b.write(&mut [0u8; 64]), Err(EthernetError::TooManyVlanTags));
+
+    for t in [
+        0,
+        0x05dc,
+        0x05ff,
+        ethernet::ETHERTYPE_VLAN,
+        ethernet::ETHERTYPE_QINQ,
+    ] {
+        assert_eq!(
+            EthernetBuilder::new(DST, SRC, t).write(&mut [0u8; 64]),
+            Err(EthernetError::InvalidTypeOrLength(t))
+        );
+    }
+    // The same values are fine once the builder is switched to 802.3.
+    let llc = Llc {
+        dsap: 0x42,
+        ssap: 0x42,
+        control: LlcControl::Unnumbered(0x03),
+    };
+    assert_eq!(
+        EthernetBuilder::new(DST, SRC, 0)
+            .llc(llc, 0)
+            .write(&mut [0u8; 64]),
+        Ok(17)
+    );
+}
+
+#[test]
+fn ieee8023_default_llc() {
+    let mut buf = [0u8; 32];
+    let len = EthernetBuilder::ieee8023(DST, SRC).write(&mut buf).unwrap();
+    assert_eq!(len, 17);
+    let h = EthernetHeader::parse(&buf[..len]).unwrap();
+    assert_eq!(h.destination(), DST);
+    assert_eq!(h.source(), SRC);
+    assert_eq!(h.type_or_length(), TypeOrLength::Length(3));
+    assert_eq!(
+        h.llc(),
+        Some(Llc {
+            dsap: 0,
+            ssap: 0,
+            control: LlcControl::Unnumbered(0x03)
+        })
+    );
+}
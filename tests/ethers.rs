@@ -0,0 +1,112 @@
+// ethers(5) parsing, writing and lookup tests.
+
+#![cfg(feature = "std")]
+
+use mac_addr::ethers::{self, Entry, EthersMap, Host};
+use mac_addr::{EthersError, MacAddr, MalformedEthersLine, ParseErrorKind};
+use std::net::IpAddr;
+
+const ETHERS: &str = "\
+# Lab hosts
+8:0:20:1:2:3        sparc1
+00:25:96:12:34:56   192.0.2.10   # build box
+
+  0:16:3e:0:0:1	vm1 extra-field
+02:42:ac:11:00:02   2001:db8::1
+08:00:20:01:02:03   sparc1-alias
+";
+
+#[test]
+fn streaming_parse() {
+    let entries: Vec<Entry> = ethers::parse(ETHERS.as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(entries.len(), 5);
+    assert_eq!(
+        entries[0].mac,
+        MacAddr::new(0x08, 0x00, 0x20, 0x01, 0x02, 0x03)
+    );
+    assert_eq!(entries[0].host, Host::Name("sparc1".into()));
+    assert_eq!(
+        entries[1].host,
+        Host::Ip("192.0.2.10".parse::<IpAddr>().unwrap())
+    );
+    assert_eq!(entries[2].host, Host::Name("vm1".into()));
+    assert_eq!(entries[3].host, Host::Ip("2001:db8::1".parse().unwrap()));
+}
+
+#[test]
+fn malformed_lines_report_position() {
+    let mut it = ethers::parse("# ok\n00:11:22:33:44 host\n".as_bytes());
+    match it.next() {
+        Some(Err(EthersError::Malformed {
+            line: 2,
+            kind: MalformedEthersLine::InvalidAddress(e),
+        })) => assert_eq!(e.kind(), ParseErrorKind::TooFewComponents),
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let err = EthersMap::from_reader("00:11:22:33:44:55\n".as_bytes()).unwrap_err();
+    assert!(matches!(
+        err,
+        EthersError::Malformed {
+            line: 1,
+            kind: MalformedEthersLine::MissingHost
+        }
+    ));
+    assert_eq!(ethers::parse_line("   # only a comment"), Ok(None));
+}
+
+#[test]
+fn map_lookup_both_directions() {
+    let map = EthersMap::from_reader(ETHERS.as_bytes()).unwrap();
+    assert_eq!(map.len(), 5);
+    let sparc = MacAddr::new(0x08, 0x00, 0x20, 0x01, 0x02, 0x03);
+    // The first entry for a duplicated address wins.
+    assert_eq!(map.host(sparc), Some(&Host::Name("sparc1".into())));
+    assert_eq!(map.mac_addr("sparc1"), Some(sparc));
+    assert_eq!(map.mac_addr("sparc1-alias"), Some(sparc));
+    assert_eq!(
+        map.mac_addr("2001:db8:0::1"),
+        Some(MacAddr::new(0x02, 0x42, 0xac, 0x11, 0x00, 0x02))
+    );
+    assert_eq!(map.mac_addr("unknown"), None);
+    assert_eq!(map.host(MacAddr::zero()), None);
+}
+
+#[test]
+fn write_round_trip() {
+    let map = EthersMap::from_reader(ETHERS.as_bytes()).unwrap();
+    let mut out = Vec::new();
+    map.write_to(&mut out).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    assert!(text.starts_with("08:00:20:01:02:03 sparc1\n"));
+    assert!(text.contains("00:16:3e:00:00:01 vm1\n"));
+
+    let again: EthersMap = ethers::parse(text.as_bytes()).map(Result::unwrap).collect();
+    assert_eq!(
+        again.iter().collect::<Vec<_>>(),
+        map.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn write_rejects_unreadable_hostnames() {
+    let mac = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+    for name in ["", "two words", "tab\there", "host#1", "192.0.2.1"] {
+        let entry = Entry {
+            mac,
+            host: Host::Name(name.into()),
+        };
+        assert!(!entry.host.is_valid(), "{:?}", name);
+        let err = ethers::write(Vec::new(), [&entry]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput, "{:?}", name);
+    }
+    let ok = Entry {
+        mac,
+        host: Host::Name("build-01.example".into()),
+    };
+    let mut out = Vec::new();
+    ethers::write(&mut out, [&ok]).unwrap();
+    assert_eq!(out, b"00:25:96:12:34:56 build-01.example\n");
+}
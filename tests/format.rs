@@ -0,0 +1,91 @@
+// Allocation-free formatting tests for MacFormat.
+
+use mac_addr::{MacAddr, MacAddr8, MacFormat, MacNotation, Separator};
+
+#[test]
+fn format_into_buffer_styles() {
+    let m = MacAddr::new(0x00, 0x25, 0x96, 0xab, 0xcd, 0xef);
+    let mut buf = [0u8; 32];
+
+    let cases = [
+        (MacFormat::new(), "00:25:96:ab:cd:ef"),
+        (
+            MacFormat::new()
+                .separator(Separator::Hyphen)
+                .uppercase(true),
+            "00-25-96-AB-CD-EF",
+        ),
+        (
+            MacFormat::new().separator(Separator::Dot).group_size(2),
+            "0025.96ab.cdef",
+        ),
+        (
+            MacFormat::new().separator(Separator::Colon).group_size(4),
+            "002596ab:cdef",
+        ),
+        (
+            MacFormat::new().separator(Separator::None).prefix("0x"),
+            "0x002596abcdef",
+        ),
+        (
+            MacFormat::new().separator(Separator::Space),
+            "00 25 96 ab cd ef",
+        ),
+    ];
+    for (format, expected) in cases {
+        assert_eq!(m.format_into(&format, &mut buf).unwrap(), expected);
+        assert_eq!(format.formatted_len(6), expected.len());
+        assert_eq!(m.display_with(format).to_string(), expected);
+    }
+}
+
+#[test]
+fn format_into_small_buffer_fails() {
+    let m = MacAddr::broadcast();
+    let mut buf = [0u8; 16];
+    assert!(m.format_into(&MacFormat::new(), &mut buf).is_err());
+    let mut buf = [0u8; 17];
+    assert_eq!(
+        m.format_into(&MacFormat::new(), &mut buf).unwrap(),
+        "ff:ff:ff:ff:ff:ff"
+    );
+}
+
+#[test]
+fn format_eui64() {
+    let m = MacAddr8::new(0x00, 0x25, 0x96, 0xff, 0xfe, 0x12, 0x34, 0x56);
+    let mut buf = [0u8; 32];
+    let cisco = MacFormat::new().separator(Separator::Dot).group_size(2);
+    assert_eq!(
+        m.format_into(&cisco, &mut buf).unwrap(),
+        "0025.96ff.fe12.3456"
+    );
+    assert_eq!(format!("{:X}", m), "00:25:96:FF:FE:12:34:56");
+    assert_eq!(format!("{:x}", m), "00:25:96:ff:fe:12:34:56");
+}
+
+#[test]
+fn round_trip_detected_notation() {
+    for s in [
+        "00:25:96:12:34:56",
+        "00-25-96-12-34-56",
+        "00 25 96 12 34 56",
+        "0025.9612.3456",
+        "002596123456",
+    ] {
+        let (mac, notation) = MacAddr::parse_with_notation(s).unwrap();
+        let format = MacFormat::from(notation);
+        assert_eq!(mac.display_with(format).to_string(), s);
+    }
+    assert_eq!(
+        MacFormat::from(MacNotation::Dotted),
+        MacFormat::new().separator(Separator::Dot).group_size(2)
+    );
+}
+
+#[test]
+fn upper_hex_trait() {
+    let m = MacAddr::new(0xaa, 0xbb, 0xcc, 0x0d, 0x0e, 0x0f);
+    assert_eq!(format!("{:X}", m), "AA:BB:CC:0D:0E:0F");
+    assert_eq!(format!("{:x}", m), "aa:bb:cc:0d:0e:0f");
+}
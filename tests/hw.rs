@@ -0,0 +1,58 @@
+// Generic HwAddr<N> parsing, formatting and conversion tests.
+
+use mac_addr::{HwAddr, MacAddr, MacAddr8, MacFormat, MacNotation, ParseErrorKind, Separator};
+
+const IPOIB: &str = "80:00:00:48:fe:80:00:00:00:00:00:00:00:02:c9:03:00:a1:b2:c3";
+
+#[test]
+fn parse_and_format_any_width() {
+    let a: HwAddr<20> = IPOIB.parse().unwrap();
+    assert_eq!(a.octets()[4], 0xfe);
+    assert_eq!(a.to_string(), IPOIB);
+    assert_eq!(HwAddr::<20>::LEN, 20);
+
+    let (short, notation) = HwAddr::<2>::parse_with_notation("ABCD").unwrap();
+    assert_eq!(short, HwAddr::new([0xab, 0xcd]));
+    assert_eq!(notation, MacNotation::Bare);
+    assert_eq!(format!("{:X}", short), "AB:CD");
+
+    let dotted = MacFormat::new().separator(Separator::Dot).group_size(2);
+    assert_eq!(short.display_with(dotted).to_string(), "abcd");
+    let mut buf = [0u8; 8];
+    assert_eq!(
+        short.format_into(&MacFormat::new(), &mut buf).unwrap(),
+        "ab:cd"
+    );
+    assert_eq!(a.redacted(4).to_string().matches("xx").count(), 16);
+
+    let e = "ab:cd:ef".parse::<HwAddr<2>>().unwrap_err();
+    assert_eq!(e.kind(), ParseErrorKind::TooManyComponents);
+    assert_eq!(e.expected_len(), 2);
+}
+
+#[test]
+fn classification() {
+    assert!(HwAddr::<4>::broadcast().is_broadcast());
+    assert!(HwAddr::<4>::default().is_zero());
+    assert!(HwAddr::new([0x03, 0]).is_multicast());
+    assert!(HwAddr::new([0x02, 0]).is_locally_administered());
+    assert!(HwAddr::new([0x00, 0]).is_unicast());
+    assert!(!HwAddr::<0>::new([]).is_multicast());
+}
+
+#[test]
+fn conversions() {
+    let mac = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+    let hw: HwAddr<6> = mac.into();
+    assert_eq!(hw.to_string(), mac.to_string());
+    assert_eq!(MacAddr::from(hw), mac);
+
+    let m8 = MacAddr8::new(1, 2, 3, 4, 5, 6, 7, 8);
+    assert_eq!(MacAddr8::from(HwAddr::from(m8)), m8);
+
+    let bytes: &[u8] = &[1, 2, 3];
+    assert_eq!(HwAddr::<3>::try_from(bytes), Ok(HwAddr::new([1, 2, 3])));
+    assert_eq!(HwAddr::<4>::try_from(bytes), Err(()));
+    assert_eq!(<[u8; 3]>::from(HwAddr::new([1, 2, 3])), [1, 2, 3]);
+    assert_eq!(HwAddr::new([9, 8]).as_ref(), &[9, 8]);
+}
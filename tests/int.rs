@@ -0,0 +1,58 @@
+// Integer conversion and arithmetic tests for MacAddr and MacAddr8.
+
+use mac_addr::{MacAddr, MacAddr8};
+
+#[test]
+fn u64_round_trip_is_big_endian() {
+    let m = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+    assert_eq!(m.to_u64(), 0x0025_9612_3456);
+    assert_eq!(u64::from(m), 0x0025_9612_3456);
+    assert_eq!(MacAddr::from_u64(0x0025_9612_3456), m);
+    assert_eq!(MacAddr::try_from(0x0025_9612_3456u64), Ok(m));
+
+    // Upper 16 bits are ignored by from_u64 but rejected by TryFrom.
+    assert_eq!(MacAddr::from_u64(0xabcd_0025_9612_3456), m);
+    assert_eq!(MacAddr::try_from(0x0001_0000_0000_0000u64), Err(()));
+    assert_eq!(MacAddr::broadcast().to_u64(), 0xffff_ffff_ffff);
+}
+
+#[test]
+fn arithmetic_48() {
+    let m = MacAddr::new(0x00, 0x25, 0x96, 0x00, 0x00, 0xff);
+    assert_eq!(
+        m.checked_add(1),
+        Some(MacAddr::new(0x00, 0x25, 0x96, 0x00, 0x01, 0x00))
+    );
+    assert_eq!(
+        m.checked_sub(0x100),
+        Some(MacAddr::new(0x00, 0x25, 0x95, 0xff, 0xff, 0xff))
+    );
+    assert_eq!(MacAddr::broadcast().checked_add(1), None);
+    assert_eq!(MacAddr::zero().checked_sub(1), None);
+    assert_eq!(MacAddr::broadcast().checked_add(u64::MAX), None);
+
+    assert_eq!(MacAddr::broadcast().wrapping_add(1), MacAddr::zero());
+    assert_eq!(MacAddr::zero().wrapping_sub(1), MacAddr::broadcast());
+    assert_eq!(MacAddr::broadcast().saturating_add(5), MacAddr::broadcast());
+    assert_eq!(MacAddr::zero().saturating_sub(5), MacAddr::zero());
+    assert_eq!(
+        MacAddr::zero().saturating_add(3),
+        MacAddr::new(0, 0, 0, 0, 0, 3)
+    );
+}
+
+#[test]
+fn u64_and_arithmetic_64() {
+    let m = MacAddr8::new(0x00, 0x25, 0x96, 0xff, 0xfe, 0x12, 0x34, 0x56);
+    assert_eq!(m.to_u64(), 0x0025_96ff_fe12_3456);
+    assert_eq!(MacAddr8::from(0x0025_96ff_fe12_3456u64), m);
+    assert_eq!(u64::from(m), 0x0025_96ff_fe12_3456);
+
+    let max = MacAddr8::from_u64(u64::MAX);
+    assert_eq!(max.checked_add(1), None);
+    assert_eq!(max.wrapping_add(1), MacAddr8::from_u64(0));
+    assert_eq!(max.saturating_add(1), max);
+    assert_eq!(MacAddr8::from_u64(0).checked_sub(1), None);
+    assert_eq!(MacAddr8::from_u64(0).wrapping_sub(1), max);
+    assert_eq!(m.checked_add(1).unwrap().to_u64(), m.to_u64() + 1);
+}
@@ -0,0 +1,99 @@
+// Modified EUI-64 and IPv6 address derivation tests.
+
+use mac_addr::{MacAddr, MacAddr8};
+use std::net::Ipv6Addr;
+
+const MAC: MacAddr = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+
+#[test]
+fn modified_eui64_flips_ul_bit() {
+    assert_eq!(
+        MAC.to_modified_eui64(),
+        MacAddr8::new(0x02, 0x25, 0x96, 0xff, 0xfe, 0x12, 0x34, 0x56)
+    );
+    let local = MacAddr::new(0x02, 0, 0, 0, 0, 1);
+    assert_eq!(local.to_modified_eui64().octets()[0], 0x00);
+    let e = MacAddr8::new(1, 2, 3, 4, 5, 6, 7, 8);
+    assert_eq!(e.to_modified_eui64().to_modified_eui64(), e);
+}
+
+#[test]
+fn link_local_and_prefix() {
+    let ll: Ipv6Addr = "fe80::225:96ff:fe12:3456".parse().unwrap();
+    assert_eq!(MAC.to_ipv6_link_local(), ll);
+
+    let prefix: Ipv6Addr = "2001:db8:1:2:ffff::".parse().unwrap();
+    assert_eq!(
+        MAC.to_ipv6_with_prefix(prefix, 64),
+        Some("2001:db8:1:2:225:96ff:fe12:3456".parse().unwrap())
+    );
+    assert_eq!(
+        MAC.to_ipv6_with_prefix(prefix, 48),
+        Some("2001:db8:1:0:225:96ff:fe12:3456".parse().unwrap())
+    );
+    assert_eq!(
+        MAC.to_ipv6_with_prefix(prefix, 0),
+        Some("::225:96ff:fe12:3456".parse().unwrap())
+    );
+    assert_eq!(MAC.to_ipv6_with_prefix(prefix, 65), None);
+}
+
+#[test]
+fn from_ipv6_eui64() {
+    let slaac: Ipv6Addr = "2001:db8::225:96ff:fe12:3456".parse().unwrap();
+    assert_eq!(MacAddr::from_ipv6_eui64(&slaac), Some(MAC));
+    let opaque: Ipv6Addr = "2001:db8::1c3a:9bd2:7e41:5f08".parse().unwrap();
+    assert_eq!(MacAddr::from_ipv6_eui64(&opaque), None);
+
+    let local = MacAddr::new(0x02, 0x42, 0xac, 0x11, 0x00, 0x02);
+    assert_eq!(
+        MacAddr::from_ipv6_eui64(&local.to_ipv6_link_local()),
+        Some(local)
+    );
+}
+
+#[test]
+fn ipv4_multicast_mapping() {
+    use std::net::Ipv4Addr;
+
+    let mac = MacAddr::from_ipv4_multicast(Ipv4Addr::new(224, 0, 0, 251)).unwrap();
+    assert_eq!(mac, MacAddr::new(0x01, 0x00, 0x5e, 0x00, 0x00, 0xfb));
+    // The top bit of the second octet is dropped.
+    assert_eq!(
+        MacAddr::from_ipv4_multicast(Ipv4Addr::new(239, 128, 0, 251)),
+        Some(mac)
+    );
+    assert_eq!(
+        MacAddr::from_ipv4_multicast(Ipv4Addr::new(192, 168, 0, 1)),
+        None
+    );
+
+    let groups = mac.candidate_ipv4_groups().unwrap();
+    assert!(groups.contains(&Ipv4Addr::new(224, 0, 0, 251)));
+    assert!(groups.contains(&Ipv4Addr::new(239, 128, 0, 251)));
+    assert!(groups.windows(2).all(|w| w[0] < w[1]));
+    for g in groups {
+        assert_eq!(MacAddr::from_ipv4_multicast(g), Some(mac));
+    }
+
+    assert_eq!(
+        MacAddr::new(0x01, 0x00, 0x5e, 0x80, 0, 1).candidate_ipv4_groups(),
+        None
+    );
+    assert_eq!(MAC.candidate_ipv4_groups(), None);
+}
+
+#[test]
+fn ipv6_multicast_mapping() {
+    let all_nodes: Ipv6Addr = "ff02::1".parse().unwrap();
+    assert_eq!(
+        MacAddr::from_ipv6_multicast(all_nodes),
+        Some(MacAddr::new(0x33, 0x33, 0, 0, 0, 1))
+    );
+    let solicited: Ipv6Addr = "ff02::1:ff12:3456".parse().unwrap();
+    assert_eq!(
+        MacAddr::from_ipv6_multicast(solicited),
+        Some(MacAddr::new(0x33, 0x33, 0xff, 0x12, 0x34, 0x56))
+    );
+    assert_eq!(MacAddr::from_ipv6_multicast(Ipv6Addr::LOCALHOST), None);
+}
@@ -0,0 +1,240 @@
+// DHCP lease file parser tests.
+
+#![cfg(feature = "std")]
+
+use mac_addr::leases::{self, ClientId, Lease};
+use mac_addr::{LeaseError, MacAddr, MalformedLease};
+use std::net::IpAddr;
+
+const MAC: MacAddr = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+fn kind<T: std::fmt::Debug>(r: Option<Result<T, LeaseError>>) -> MalformedLease {
+    match r {
+        Some(Err(LeaseError::Malformed { kind, .. })) => kind,
+        other => panic!("expected a malformed lease, got {:?}", other),
+    }
+}
+
+const ISC: &str = r#"
+# The format of this file is documented in the dhcpd.leases(5) manual page.
+authoring-byte-order little-endian;
+server-duid "\000\001\000\001";
+
+failover peer "dhcp" state {
+  my state normal at 4 2024/01/18 10:00:00;
+}
+
+lease 192.0.2.10 {
+  starts 4 2024/01/18 10:00:00;
+  ends 4 2024/01/18 12:00:00;
+  binding state active;
+  hardware ethernet 00:25:96:12:34:56;
+  uid "\001\000%\226\0224V";
+  client-hostname "laptop #1";
+}
+lease 192.0.2.11 {
+  ends never;
+  hardware ethernet 0:16:3e:0:0:1;
+  uid 01:00:16:3e:00:00:01;
+}
+lease 192.0.2.12 {
+  ends epoch 1705579200; # Thu Jan 18 12:00:00 2024
+  binding state free;
+}
+lease 192.0.2.13 {
+  hardware infiniband 80:00:00:48:fe:80;
+}
+lease 192.0.2.14 {
+  hardware ethernet 00:25:96:12:34:57;
+  uid "\000host-a";
+}
+lease 192.0.2.15 {
+  hardware ethernet 00:25:96:12:34:58;
+  uid ff:12:34:56:78:0:1:0:1:2d:3e;
+}
+"#;
+
+#[test]
+fn isc_dhcpd_leases() {
+    let mut it = leases::parse_isc(ISC.as_bytes());
+    let first = it.next().unwrap().unwrap();
+    assert_eq!(
+        first,
+        Lease {
+            mac: MAC,
+            ip: ip("192.0.2.10"),
+            hostname: Some("laptop #1".into()),
+            expires: Some(1705579200),
+            client_id: Some(ClientId::Ethernet(MAC)),
+        }
+    );
+    let second = it.next().unwrap().unwrap();
+    assert_eq!(second.mac, MacAddr::new(0x00, 0x16, 0x3e, 0, 0, 1));
+    assert_eq!(second.expires, None);
+    assert_eq!(second.client_id, Some(ClientId::Ethernet(second.mac)));
+    assert_eq!(kind(it.next()), MalformedLease::NoHardwareAddress);
+    assert_eq!(kind(it.next()), MalformedLease::NonEthernet(32));
+    assert_eq!(
+        it.next().unwrap().unwrap().client_id,
+        Some(ClientId::Other(0, b"host-a".to_vec()))
+    );
+    assert_eq!(
+        it.next().unwrap().unwrap().client_id,
+        Some(ClientId::Duid {
+            iaid: 0x12345678,
+            duid: vec![0x00, 0x01, 0x00, 0x01, 0x2d, 0x3e],
+        })
+    );
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn isc_dates_are_checked_against_the_calendar() {
+    let lease = |ends: &str| {
+        let text = format!(
+            "lease 192.0.2.10 {{\n  ends {};\n  hardware ethernet 00:25:96:12:34:56;\n}}\n",
+            ends
+        );
+        leases::parse_isc(text.as_bytes()).next().unwrap()
+    };
+    assert_eq!(
+        lease("4 2024/02/29 00:00:00").unwrap().expires,
+        Some(1709164800)
+    );
+    for ends in [
+        "4 2024/02/30 00:00:00",
+        "4 2024/02/31 00:00:00",
+        "4 2023/02/29 00:00:00",
+        "4 2100/02/29 00:00:00",
+        "4 2024/04/31 00:00:00",
+        "4 2024/01/00 00:00:00",
+    ] {
+        assert_eq!(
+            kind(Some(lease(ends))),
+            MalformedLease::InvalidTime,
+            "{}",
+            ends
+        );
+    }
+    assert!(lease("4 2000/02/29 00:00:00").is_ok());
+    assert!(lease("4 2024/12/31 23:59:59").is_ok());
+}
+
+#[test]
+fn dnsmasq_leases() {
+    let text = "\
+1705579200 00:25:96:12:34:56 192.0.2.10 laptop 01:00:25:96:12:34:56
+0 02:42:ac:11:00:02 192.0.2.11 * *
+1705579200 20-80:00:00:48:fe:80 192.0.2.12 ib0 *
+duid 00:01:00:01:2d:3e:4f:50:00:25:96:12:34:56
+1705579200 305419896 2001:db8::10 laptop 00:01:00:01:2d:3e
+1705579200 00:25:96:12:34 192.0.2.13 bad *
+1705579200 00:25:96:12:34:57 192.0.2.14 * ff:00:00:00:01:00:01:00:01
+1705579200 00:25:96:12:34:58 192.0.2.15 * 00:68:6f:73:74
+1705579200 00:25:96:12:34:59 192.0.2.16 * 01:00:25:96
+";
+    let mut it = leases::parse_dnsmasq(text.as_bytes());
+    let first = it.next().unwrap().unwrap();
+    assert_eq!(first.mac, MAC);
+    assert_eq!(first.client_id, Some(ClientId::Ethernet(MAC)));
+    assert_eq!(first.hostname.as_deref(), Some("laptop"));
+    assert_eq!(first.expires, Some(1705579200));
+    let second = it.next().unwrap().unwrap();
+    assert_eq!(second.hostname, None);
+    assert_eq!(second.expires, None);
+    assert_eq!(second.client_id, None);
+    assert_eq!(kind(it.next()), MalformedLease::NonEthernet(0x20));
+    assert_eq!(kind(it.next()), MalformedLease::NoHardwareAddress);
+    match it.next() {
+        Some(Err(LeaseError::Malformed { line, kind })) => {
+            assert_eq!(line, 6);
+            assert_eq!(kind, MalformedLease::InvalidAddress);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    let ids: Vec<_> = it.map(|l| l.unwrap().client_id.unwrap()).collect();
+    assert_eq!(
+        ids,
+        [
+            ClientId::Duid {
+                iaid: 1,
+                duid: vec![0x00, 0x01, 0x00, 0x01],
+            },
+            ClientId::Other(0, b"host".to_vec()),
+            ClientId::Other(1, vec![0x00, 0x25, 0x96]),
+        ]
+    );
+}
+
+#[test]
+fn dnsmasq_hardware_edge_cases() {
+    let text = "\
+1705579200 T305419896 2001:db8::20 tmp 00:01:00:01:2d:3e
+1705579200 01- 192.0.2.20 nohw *
+1705579200 06-ab 192.0.2.21 tr *
+1705579200 01-00:25:96:12:34:56 192.0.2.22 eth *
+1705579200 T12ab 192.0.2.23 bad *
+";
+    let mut it = leases::parse_dnsmasq(text.as_bytes());
+    assert_eq!(kind(it.next()), MalformedLease::NoHardwareAddress);
+    assert_eq!(kind(it.next()), MalformedLease::NoHardwareAddress);
+    assert_eq!(kind(it.next()), MalformedLease::NonEthernet(6));
+    assert_eq!(it.next().unwrap().unwrap().mac, MAC);
+    assert_eq!(kind(it.next()), MalformedLease::InvalidAddress);
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn kea_memfile_csv() {
+    let v4 = "\
+address,hwaddr,client_id,valid_lifetime,expire,subnet_id,fqdn_fwd,fqdn_rev,hostname,state,user_context,pool_id
+192.0.2.10,00:25:96:12:34:56,01:00:25:96:12:34:56,3600,1705579200,1,0,0,laptop&#x2c office,0,,0
+192.0.2.11,02:42:ac:11:00:02,,4294967295,1705579200,1,0,0,,0,,0
+192.0.2.12,02:42:ac:11:00:03,ff:00:00:00:07:00:03:00:01:02:42:ac:11:00:03,3600,1705579200,1,0,0,,0,,0
+192.0.2.13,02:42:ac:11:00:04,00:68:6f:73:74,3600,1705579200,1,0,0,,0,,0
+";
+    let all: Vec<Lease> = leases::parse_kea(v4.as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(all[0].mac, MAC);
+    assert_eq!(all[0].hostname.as_deref(), Some("laptop, office"));
+    assert_eq!(all[0].client_id, Some(ClientId::Ethernet(MAC)));
+    assert_eq!(all[1].client_id, None);
+    assert_eq!(
+        all[2].client_id,
+        Some(ClientId::Duid {
+            iaid: 7,
+            duid: vec![0x00, 0x03, 0x00, 0x01, 0x02, 0x42, 0xac, 0x11, 0x00, 0x03],
+        })
+    );
+    assert_eq!(all[3].client_id, Some(ClientId::Other(0, b"host".to_vec())));
+    for lease in &all[..4] {
+        if let Some(id) = &lease.client_id {
+            assert_eq!(ClientId::from_bytes(&id.to_bytes()).as_ref(), Some(id));
+        }
+    }
+    assert_eq!(all[1].expires, None);
+    assert_eq!(all[1].hostname, None);
+
+    let v6 = "\
+address,duid,valid_lifetime,expire,subnet_id,pref_lifetime,lease_type,iaid,prefix_len,fqdn_fwd,fqdn_rev,hostname,hwaddr,state,user_context,hwtype,hwaddr_source,pool_id
+2001:db8::10,00:01:00:01,3600,1705579200,1,1800,0,1,128,0,0,host6,00:25:96:12:34:56,0,,1,2,0
+2001:db8::11,00:01:00:02,3600,1705579200,1,1800,0,1,128,0,0,,,0,,,0,0
+2001:db8::12,00:01:00:03,3600,1705579200,1,1800,0,1,128,0,0,,80:00:00:48:fe:80,0,,32,2,0
+";
+    let mut it = leases::parse_kea(v6.as_bytes());
+    let lease = it.next().unwrap().unwrap();
+    assert_eq!(lease.ip, ip("2001:db8::10"));
+    assert_eq!(lease.mac, MAC);
+    assert_eq!(kind(it.next()), MalformedLease::NoHardwareAddress);
+    assert_eq!(kind(it.next()), MalformedLease::NonEthernet(32));
+
+    assert_eq!(
+        kind(leases::parse_kea("not,a,header\n".as_bytes()).next()),
+        MalformedLease::MissingField
+    );
+}
@@ -0,0 +1,64 @@
+// Neighbor table parsing and query tests (Linux, `sys` feature).
+
+#![cfg(all(feature = "sys", target_os = "linux"))]
+
+use mac_addr::MacAddr;
+use mac_addr::sys::{self, NeighborState, parse_proc_net_arp};
+use std::net::IpAddr;
+
+const PROC_NET_ARP: &str = "\
+IP address       HW type     Flags       HW address            Mask     Device
+192.168.1.1      0x1         0x2         00:25:96:12:34:56     *        eth0
+192.168.1.20     0x1         0x0         00:00:00:00:00:00     *        eth0
+10.0.0.7         0x1         0x6         02:42:ac:11:00:02     *        docker0
+10.0.0.8         0x1         0xc         52:54:00:aa:bb:cc     *        br0
+";
+
+#[test]
+fn parse_fixture() {
+    let table = parse_proc_net_arp(PROC_NET_ARP).unwrap();
+    assert_eq!(table.len(), 4);
+
+    assert_eq!(table[0].ip(), "192.168.1.1".parse::<IpAddr>().unwrap());
+    assert_eq!(
+        table[0].mac_addr(),
+        Some(MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56))
+    );
+    assert_eq!(table[0].interface(), "eth0");
+    assert_eq!(table[0].ifindex(), None);
+    assert_eq!(table[0].state(), NeighborState::Reachable);
+
+    assert_eq!(table[1].mac_addr(), None);
+    assert_eq!(table[1].state(), NeighborState::Incomplete);
+
+    assert_eq!(table[2].state(), NeighborState::Permanent);
+    assert_eq!(table[2].interface(), "docker0");
+    assert!(!table[2].is_proxy());
+
+    assert_eq!(table[3].state(), NeighborState::Permanent);
+    assert!(table[3].is_proxy());
+    assert!(!table[3].is_router());
+}
+
+#[test]
+fn parse_rejects_malformed_lines() {
+    assert!(parse_proc_net_arp("").unwrap().is_empty());
+    let e: mac_addr::ArpParseError =
+        parse_proc_net_arp("IP address HW type\n192.168.1.1 0x1 0x2\n").unwrap_err();
+    assert_eq!(e.line(), 2);
+    let e = parse_proc_net_arp("not-an-ip 0x1 0x2 00:00:00:00:00:01 * eth0\n").unwrap_err();
+    assert_eq!(e.line(), 1);
+}
+
+#[test]
+fn live_tables() {
+    // Contents depend on the host; both sources must at least be readable.
+    for n in sys::arp_table().unwrap() {
+        assert!(n.ip().is_ipv4());
+    }
+    for n in sys::neighbors().unwrap() {
+        assert!(n.ifindex().is_some());
+        assert!(!n.interface().is_empty());
+    }
+    assert_eq!(NeighborState::from_nud(0x04), NeighborState::Stale);
+}
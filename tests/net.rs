@@ -0,0 +1,114 @@
+// MacNet prefix block tests.
+
+use mac_addr::{MacAddr, MacAddr8, MacNet, MacNetError, MacRange};
+
+#[test]
+fn parse_prefix_length_and_mask() {
+    let ma_l: MacNet<MacAddr> = "00:25:96:12:34:56/24".parse().unwrap();
+    assert_eq!(ma_l.prefix_len(), 24);
+    assert_eq!(ma_l.mask(), "ff:ff:ff:00:00:00".parse().unwrap());
+    assert_eq!(ma_l.network(), "00:25:96:00:00:00".parse().unwrap());
+    assert_eq!(ma_l.broadcast(), "00:25:96:ff:ff:ff".parse().unwrap());
+    assert_eq!(ma_l.to_string(), "00:25:96:12:34:56/24");
+    assert_eq!(ma_l.trunc().to_string(), "00:25:96:00:00:00/24");
+
+    let ma_m: MacNet<MacAddr> = "00:25:96:12:30:00/ff:ff:ff:ff:f0:00".parse().unwrap();
+    assert_eq!(ma_m.prefix_len(), 36);
+    let dotted: MacNet<MacAddr> = "0025.9612.3000/ffff.ffff.f000".parse().unwrap();
+    assert_eq!(dotted, ma_m);
+
+    assert_eq!(
+        "00:25:96:00:00:00/ff:00:ff:00:00:00"
+            .parse::<MacNet<MacAddr>>()
+            .unwrap_err(),
+        MacNetError::NonContiguousMask
+    );
+    assert_eq!(
+        "00:25:96:00:00:00/49"
+            .parse::<MacNet<MacAddr>>()
+            .unwrap_err(),
+        MacNetError::InvalidPrefixLength
+    );
+    for long in ["00:25:96:00:00:00/100", "00:25:96:00:00:00/999"] {
+        assert_eq!(
+            long.parse::<MacNet<MacAddr>>().unwrap_err(),
+            MacNetError::InvalidPrefixLength
+        );
+    }
+    let padded: MacNet<MacAddr> = "00:25:96:00:00:00/024".parse().unwrap();
+    assert_eq!(padded.prefix_len(), 24);
+    // Bare masks made only of digits are still masks.
+    let bare: MacNet<MacAddr> = "00:00:00:00:00:00/800000000000".parse().unwrap();
+    assert_eq!(bare.prefix_len(), 1);
+    let bare8: MacNet<MacAddr8> = "00:00:00:00:00:00:00:00/8000000000000000".parse().unwrap();
+    assert_eq!(bare8.prefix_len(), 1);
+    let zero: MacNet<MacAddr> = "00:25:96:00:00:00/000000000000".parse().unwrap();
+    assert_eq!(zero.prefix_len(), 0);
+    assert_eq!(
+        "00:25:96:00:00:00".parse::<MacNet<MacAddr>>().unwrap_err(),
+        MacNetError::MissingPrefix
+    );
+    assert!(matches!(
+        "00:25:96:00:00/24".parse::<MacNet<MacAddr>>().unwrap_err(),
+        MacNetError::InvalidAddress(_)
+    ));
+}
+
+#[test]
+fn contains_and_hosts() {
+    let net = MacNet::new(MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x50), 44).unwrap();
+    assert!(net.contains(MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x5f)));
+    assert!(!net.contains(MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x60)));
+    assert_eq!(
+        net.hosts(),
+        MacRange::new(
+            MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x50),
+            MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x5f)
+        )
+    );
+    assert_eq!(net.hosts().count(), 16);
+
+    let all = MacNet::new(MacAddr::zero(), 0).unwrap();
+    assert!(all.contains(MacAddr::broadcast()));
+    assert_eq!(all.hosts().num_addresses(), 1 << 48);
+    let one = MacNet::new(MacAddr::broadcast(), 48).unwrap();
+    assert_eq!(one.hosts().count(), 1);
+    assert!(all.contains_net(&one));
+    assert!(!one.contains_net(&all));
+}
+
+#[test]
+fn subnetting() {
+    let net: MacNet<MacAddr> = "00:25:96:00:00:00/24".parse().unwrap();
+    let subs: Vec<_> = net.subnets(26).unwrap().collect();
+    assert_eq!(subs.len(), 4);
+    assert_eq!(subs[0].to_string(), "00:25:96:00:00:00/26");
+    assert_eq!(subs[3].to_string(), "00:25:96:c0:00:00/26");
+    assert!(net.subnets(23).is_err());
+    assert!(net.subnets(49).is_err());
+    assert_eq!(net.subnets(24).unwrap().count(), 1);
+
+    let top = MacNet::new(MacAddr::broadcast(), 46).unwrap();
+    let subs: Vec<_> = top.subnets(48).unwrap().collect();
+    assert_eq!(subs.len(), 4);
+    assert_eq!(subs[3].network(), MacAddr::broadcast());
+}
+
+#[test]
+fn eui64_blocks() {
+    let net: MacNet<MacAddr8> = "00:25:96:ff:fe:00:00:00/40".parse().unwrap();
+    assert!(net.contains(MacAddr8::new(
+        0x00, 0x25, 0x96, 0xff, 0xfe, 0x12, 0x34, 0x56
+    )));
+    assert_eq!(net.hosts().num_addresses(), 1 << 24);
+    let all = MacNet::new(MacAddr8::from_u64(0), 0).unwrap();
+    assert_eq!(all.broadcast(), MacAddr8::from_u64(u64::MAX));
+    assert_eq!(all.subnets(1).unwrap().count(), 2);
+    assert_eq!(
+        MacNet::with_mask(MacAddr8::from_u64(0), MacAddr8::from_u64(u64::MAX))
+            .unwrap()
+            .prefix_len(),
+        64
+    );
+    assert!(MacNet::new(MacAddr8::from_u64(0), 65).is_err());
+}
@@ -0,0 +1,76 @@
+// Bitwise operator and mask helper tests.
+
+use mac_addr::{MacAddr, MacAddr8};
+
+#[test]
+fn bitwise_operators() {
+    let a = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+    let mask = MacAddr::new(0xff, 0xff, 0xff, 0x00, 0x00, 0x00);
+    assert_eq!(a & mask, MacAddr::new(0x00, 0x25, 0x96, 0, 0, 0));
+    assert_eq!(a | !mask, MacAddr::new(0x00, 0x25, 0x96, 0xff, 0xff, 0xff));
+    assert_eq!(a ^ a, MacAddr::zero());
+    assert_eq!(!MacAddr::zero(), MacAddr::broadcast());
+
+    let mut b = a;
+    b &= mask;
+    assert_eq!(b, a & mask);
+    b |= MacAddr::new(0, 0, 0, 0, 0, 1);
+    assert_eq!(b.octets()[5], 1);
+    b ^= b;
+    assert_eq!(b, MacAddr::zero());
+
+    let m8 = MacAddr8::new(0x00, 0x25, 0x96, 0xff, 0xfe, 0x12, 0x34, 0x56);
+    assert_eq!(!!m8, m8);
+    assert_eq!(
+        m8 & MacAddr8::mask_from_prefix_len(24).unwrap(),
+        MacAddr8::new(0x00, 0x25, 0x96, 0, 0, 0, 0, 0)
+    );
+}
+
+#[test]
+fn masks_from_and_to_prefix_len() {
+    assert_eq!(MacAddr::mask_from_prefix_len(0), Some(MacAddr::zero()));
+    assert_eq!(
+        MacAddr::mask_from_prefix_len(28),
+        Some(MacAddr::new(0xff, 0xff, 0xff, 0xf0, 0, 0))
+    );
+    assert_eq!(
+        MacAddr::mask_from_prefix_len(48),
+        Some(MacAddr::broadcast())
+    );
+    assert_eq!(MacAddr::mask_from_prefix_len(49), None);
+    assert_eq!(
+        MacAddr8::mask_from_prefix_len(64),
+        Some(MacAddr8::from_u64(u64::MAX))
+    );
+    assert_eq!(MacAddr8::mask_from_prefix_len(65), None);
+
+    for n in 0..=48 {
+        let mask = MacAddr::mask_from_prefix_len(n).unwrap();
+        assert_eq!(mask.prefix_len_of_mask(), Some(n));
+    }
+    for n in 0..=64 {
+        let mask = MacAddr8::mask_from_prefix_len(n).unwrap();
+        assert_eq!(mask.prefix_len_of_mask(), Some(n));
+    }
+    assert_eq!(
+        MacAddr::new(0xff, 0x00, 0xff, 0, 0, 0).prefix_len_of_mask(),
+        None
+    );
+    assert_eq!(MacAddr::new(0, 0, 0, 0, 0, 1).prefix_len_of_mask(), None);
+    assert_eq!(
+        MacAddr8::new(0xff, 0, 0, 0, 0, 0, 0, 1).prefix_len_of_mask(),
+        None
+    );
+}
+
+#[test]
+fn matches_masked() {
+    let vrrp = MacAddr::new(0x00, 0x00, 0x5e, 0x00, 0x01, 0x00);
+    let mask = MacAddr::mask_from_prefix_len(40).unwrap();
+    assert!(MacAddr::new(0x00, 0x00, 0x5e, 0x00, 0x01, 0x2a).matches_masked(vrrp, mask));
+    assert!(!MacAddr::new(0x00, 0x00, 0x5e, 0x00, 0x02, 0x2a).matches_masked(vrrp, mask));
+    // The multicast bit alone.
+    let mc = MacAddr::new(0x01, 0, 0, 0, 0, 0);
+    assert!(MacAddr::new(0x33, 0x33, 0, 0, 0, 1).matches_masked(mc, mc));
+}
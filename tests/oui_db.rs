@@ -0,0 +1,126 @@
+// Embedded IEEE registry lookups (only built when the `oui-db` feature is enabled).
+
+#![cfg(feature = "oui-db")]
+
+use mac_addr::{MacAddr, MacAddr8, Registry};
+
+#[test]
+fn lookup_known_vendor() {
+    let mac: MacAddr = "00:50:56:c0:00:08".parse().unwrap();
+    let org = mac.vendor().unwrap();
+    assert_eq!(org.name(), "VMware, Inc.");
+    assert_eq!(org.registry(), Registry::MaL);
+    assert_eq!(org.block_size(), 1 << 24);
+    assert_eq!(org.prefix().to_string(), "00:50:56:00:00:00/24");
+    assert!(org.prefix().contains(mac));
+
+    let virtualbox: MacAddr = "08:00:27:12:34:56".parse().unwrap();
+    assert_eq!(
+        virtualbox.vendor().unwrap().name(),
+        "PCS Systemtechnik GmbH"
+    );
+}
+
+#[test]
+fn ma_s_overrides_enclosing_ma_l() {
+    let mac: MacAddr = "00:1b:c5:00:0a:bc".parse().unwrap();
+    let org = mac.vendor().unwrap();
+    assert_eq!(org.name(), "Converging Systems Inc.");
+    assert_eq!(org.registry(), Registry::MaS);
+    assert_eq!(org.prefix().to_string(), "00:1b:c5:00:00:00/36");
+
+    // Outside the MA-S block the enclosing MA-L applies.
+    let parent = "00:1b:c5:ff:f0:00"
+        .parse::<MacAddr>()
+        .unwrap()
+        .vendor()
+        .unwrap();
+    assert_eq!(parent.name(), "IEEE Registration Authority");
+    assert_eq!(parent.registry(), Registry::MaL);
+}
+
+#[test]
+fn ma_m_overrides_enclosing_ma_l() {
+    let mac: MacAddr = "00:55:da:0f:ff:ff".parse().unwrap();
+    let org = mac.vendor().unwrap();
+    assert_eq!(org.name(), "Shinko Technos co.,ltd.");
+    assert_eq!(org.registry(), Registry::MaM);
+    assert_eq!(org.block_size(), 1 << 20);
+    assert_eq!(org.prefix().to_string(), "00:55:da:00:00:00/28");
+
+    let parent = "00:55:da:10:00:00"
+        .parse::<MacAddr>()
+        .unwrap()
+        .vendor()
+        .unwrap();
+    assert_eq!(parent.registry(), Registry::MaL);
+}
+
+#[test]
+fn iab_overrides_enclosing_ma_l() {
+    let org = "00:50:c2:00:0f:ff"
+        .parse::<MacAddr>()
+        .unwrap()
+        .vendor()
+        .unwrap();
+    assert_eq!(org.name(), "T.L.S. Corp.");
+    assert_eq!(org.registry(), Registry::Iab);
+    assert_eq!(org.block_size(), 1 << 12);
+
+    let parent = "00:50:c2:00:10:00"
+        .parse::<MacAddr>()
+        .unwrap()
+        .vendor()
+        .unwrap();
+    assert_eq!(parent.registry(), Registry::MaL);
+}
+
+#[test]
+fn lookup_vendor_from_every_registry() {
+    // The checked-in CID snapshot is empty, so there is no CID row to look up.
+    let cases = [
+        ("00:00:0c:12:34:56", Registry::MaL, "Cisco Systems, Inc"),
+        (
+            "00:55:da:01:23:45",
+            Registry::MaM,
+            "Shinko Technos co.,ltd.",
+        ),
+        (
+            "00:1b:c5:00:01:23",
+            Registry::MaS,
+            "Converging Systems Inc.",
+        ),
+        ("00:50:c2:00:01:23", Registry::Iab, "T.L.S. Corp."),
+    ];
+    for (s, registry, name) in cases {
+        let org = s.parse::<MacAddr>().unwrap().vendor().unwrap();
+        assert_eq!(org.registry(), registry, "{}", s);
+        assert_eq!(org.name(), name, "{}", s);
+        assert_eq!(org.prefix().prefix_len(), registry.prefix_len(), "{}", s);
+    }
+}
+
+#[test]
+fn lookup_unknown_is_none() {
+    assert!(
+        MacAddr::new(0x02, 0x00, 0x00, 0x00, 0x00, 0x01)
+            .vendor()
+            .is_none()
+    );
+    assert!(MacAddr::broadcast().vendor().is_none());
+}
+
+#[test]
+fn lookup_eui64_uses_leading_bits() {
+    let m8 = MacAddr8::new(0x00, 0x15, 0x5d, 0xff, 0xfe, 0x01, 0x02, 0x03);
+    assert_eq!(m8.vendor().unwrap().name(), "Microsoft Corporation");
+}
+
+#[test]
+fn registry_block_sizes() {
+    assert_eq!(Registry::MaL.block_size(), 1 << 24);
+    assert_eq!(Registry::MaM.block_size(), 1 << 20);
+    assert_eq!(Registry::MaS.block_size(), 1 << 12);
+    assert_eq!(Registry::Iab.prefix_len(), 36);
+    assert_eq!(Registry::Cid.as_str(), "CID");
+}
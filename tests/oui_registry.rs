@@ -0,0 +1,159 @@
+// Runtime IEEE CSV and Wireshark manuf loading (requires std).
+
+#![cfg(feature = "std")]
+
+use mac_addr::{MacAddr, MalformedLine, OuiRegistry, Registry, RegistryError};
+
+const OUI_CSV: &str = "\
+Registry,Assignment,Organization Name,Organization Address
+MA-L,70B3D5,IEEE Registration Authority,445 Hoes Lane Piscataway NJ US 08554
+MA-L,005056,\"VMware, Inc.\",3401 Hillview Avenue PALO ALTO CA US 94304
+";
+
+const MAM_CSV: &str = "\
+Registry,Assignment,Organization Name,Organization Address
+MA-M,70B3D51,\"Example \"\"Medium\"\" Block, Ltd\",Somewhere
+";
+
+const OUI36_CSV: &str = "\
+Registry,Assignment,Organization Name,Organization Address
+MA-S,70B3D5123,Small Block Co,Elsewhere
+";
+
+fn mac(s: &str) -> MacAddr {
+    s.parse().unwrap()
+}
+
+#[test]
+fn ieee_csv_longest_prefix() {
+    let mut reg = OuiRegistry::new();
+    assert_eq!(reg.load_ieee_csv(OUI_CSV.as_bytes()).unwrap(), 2);
+    assert_eq!(reg.load_ieee_csv(MAM_CSV.as_bytes()).unwrap(), 1);
+    assert_eq!(reg.load_ieee_csv(OUI36_CSV.as_bytes()).unwrap(), 1);
+    assert_eq!(reg.len(), 4);
+
+    let small = reg.lookup(mac("70:b3:d5:12:34:56")).unwrap();
+    assert_eq!(small.name(), "Small Block Co");
+    assert_eq!(small.registry(), Registry::MaS);
+    assert_eq!(small.prefix().to_string(), "70:b3:d5:12:30:00/36");
+    assert_eq!(small.block_size(), 4096);
+
+    let medium = reg.lookup(mac("70:b3:d5:1f:ff:ff")).unwrap();
+    assert_eq!(medium.name(), "Example \"Medium\" Block, Ltd");
+    assert_eq!(medium.registry(), Registry::MaM);
+
+    let large = reg.lookup(mac("70:b3:d5:20:00:00")).unwrap();
+    assert_eq!(large.name(), "IEEE Registration Authority");
+    assert_eq!(large.registry(), Registry::MaL);
+
+    assert_eq!(
+        reg.lookup(mac("00:50:56:00:00:01")).unwrap().name(),
+        "VMware, Inc."
+    );
+    assert!(reg.lookup(mac("02:00:00:00:00:01")).is_none());
+}
+
+#[test]
+fn ieee_csv_reports_line_numbers() {
+    let bad = "Registry,Assignment,Organization Name,Organization Address\n\
+               MA-L,005056,\"VMware, Inc.\",x\n\
+               MA-L,00505,Short,x\n";
+    match OuiRegistry::new().load_ieee_csv(bad.as_bytes()) {
+        Err(RegistryError::Malformed { line, kind }) => {
+            assert_eq!(line, 3);
+            assert_eq!(kind, MalformedLine::UnsupportedPrefixLength);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let cases = [
+        ("XX-L,005056,Name,x\n", MalformedLine::UnknownRegistry),
+        ("MA-L,00505G,Name,x\n", MalformedLine::InvalidPrefix),
+        ("MA-L,005056\n", MalformedLine::MissingField),
+        ("MA-L,005056,\"Name,x\n", MalformedLine::UnterminatedQuote),
+    ];
+    for (input, expected) in cases {
+        let err = OuiRegistry::new()
+            .load_ieee_csv(input.as_bytes())
+            .unwrap_err();
+        assert!(
+            matches!(err, RegistryError::Malformed { line: 1, kind } if kind == expected),
+            "input={:?} err={}",
+            input,
+            err
+        );
+    }
+}
+
+#[test]
+fn manuf_formats() {
+    let manuf = "\
+# Wireshark manuf excerpt
+00:00:0C\tCisco\tCisco Systems, Inc
+00:50:56\tVMware\t# VMware, Inc.
+08:00:27\tPCSSyste
+
+00:1B:C5:00:00:00/36\tConverg\tConverging Systems Inc.
+00:55:DA:10:00:00/28\tMedium
+";
+    let mut reg = OuiRegistry::new();
+    assert_eq!(reg.load_manuf(manuf.as_bytes()).unwrap(), 5);
+    assert_eq!(
+        reg.lookup(mac("00:00:0c:07:ac:01")).unwrap().name(),
+        "Cisco Systems, Inc"
+    );
+    assert_eq!(
+        reg.lookup(mac("00:50:56:00:00:01")).unwrap().name(),
+        "VMware, Inc."
+    );
+    assert_eq!(
+        reg.lookup(mac("08:00:27:00:00:01")).unwrap().name(),
+        "PCSSyste"
+    );
+    let converg = reg.lookup(mac("00:1b:c5:00:00:42")).unwrap();
+    assert_eq!(converg.name(), "Converging Systems Inc.");
+    assert_eq!(converg.registry(), Registry::MaS);
+    assert!(reg.lookup(mac("00:1b:c5:00:10:00")).is_none());
+    assert_eq!(
+        reg.lookup(mac("00:55:da:1f:00:00")).unwrap().registry(),
+        Registry::MaM
+    );
+}
+
+#[test]
+fn manuf_reports_line_numbers() {
+    let manuf = "00:00:0C\tCisco\n00:00:0C:00:00:00/20\tOdd\n";
+    let err = OuiRegistry::new().load_manuf(manuf.as_bytes()).unwrap_err();
+    assert!(matches!(
+        err,
+        RegistryError::Malformed {
+            line: 2,
+            kind: MalformedLine::UnsupportedPrefixLength
+        }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "Malformed registry line 2: unsupported prefix length"
+    );
+
+    let err = OuiRegistry::new()
+        .load_manuf("zz:00:0C\tBad\n".as_bytes())
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        RegistryError::Malformed {
+            line: 1,
+            kind: MalformedLine::InvalidPrefix
+        }
+    ));
+    let err = OuiRegistry::new()
+        .load_manuf("00:00:0C\n".as_bytes())
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        RegistryError::Malformed {
+            line: 1,
+            kind: MalformedLine::MissingField
+        }
+    ));
+}
@@ -0,0 +1,182 @@
+// Multi-notation parsing tests for MacAddr and MacAddr8.
+
+use mac_addr::{MacAddr, MacAddr8, MacNotation, ParseErrorKind, ParseMode};
+
+const OCTETS: [u8; 6] = [0x00, 0x25, 0x96, 0x12, 0x34, 0x56];
+
+#[test]
+fn parse_all_notations() {
+    let cases = [
+        ("00:25:96:12:34:56", MacNotation::Colon),
+        ("00-25-96-12-34-56", MacNotation::Hyphen),
+        ("00 25 96 12 34 56", MacNotation::Space),
+        ("0025.9612.3456", MacNotation::Dotted),
+        ("002596123456", MacNotation::Bare),
+    ];
+    for (s, notation) in cases {
+        let (mac, seen) = MacAddr::parse_with_notation(s).unwrap();
+        assert_eq!(mac.octets(), OCTETS, "input={}", s);
+        assert_eq!(seen, notation, "input={}", s);
+        assert_eq!(s.parse::<MacAddr>().unwrap(), mac);
+    }
+}
+
+#[test]
+fn parse_single_digit_octets() {
+    let mac: MacAddr = "0:1:2:a:b:c".parse().unwrap();
+    assert_eq!(mac.octets(), [0, 1, 2, 0xa, 0xb, 0xc]);
+    let mac: MacAddr = "0-1-2-a-b-c".parse().unwrap();
+    assert_eq!(mac.octets(), [0, 1, 2, 0xa, 0xb, 0xc]);
+}
+
+#[test]
+fn parse_rejects_inconsistent_or_malformed() {
+    for s in ["00:25-96:12:34:56", "0025.9612:3456"] {
+        assert_eq!(
+            s.parse::<MacAddr>().unwrap_err().kind(),
+            ParseErrorKind::MixedSeparators,
+            "input={:?}",
+            s
+        );
+    }
+    for s in [
+        "025.9612.3456",
+        "00:25:96:12:34:",
+        "000:25:96:12:34:56",
+        "00_25_96_12_34_56",
+        "+0:25:96:12:34:56",
+        "",
+    ] {
+        assert_eq!(
+            s.parse::<MacAddr>().unwrap_err().kind(),
+            ParseErrorKind::InvalidComponent,
+            "input={:?}",
+            s
+        );
+    }
+    assert_eq!(
+        "0025.9612".parse::<MacAddr>().unwrap_err().kind(),
+        ParseErrorKind::TooFewComponents
+    );
+    assert_eq!(
+        "0025.9612.3456.7890".parse::<MacAddr>().unwrap_err().kind(),
+        ParseErrorKind::TooManyComponents
+    );
+    assert_eq!(
+        "0025961234".parse::<MacAddr>().unwrap_err().kind(),
+        ParseErrorKind::TooFewComponents
+    );
+    assert_eq!(
+        "00259612345678".parse::<MacAddr>().unwrap_err().kind(),
+        ParseErrorKind::TooManyComponents
+    );
+}
+
+#[test]
+fn parse_all_notations_8() {
+    let expected = [0x00, 0x25, 0x96, 0xff, 0xfe, 0x12, 0x34, 0x56];
+    let cases = [
+        ("00:25:96:ff:fe:12:34:56", MacNotation::Colon),
+        ("00-25-96-FF-FE-12-34-56", MacNotation::Hyphen),
+        ("00 25 96 ff fe 12 34 56", MacNotation::Space),
+        ("0025.96ff.fe12.3456", MacNotation::Dotted),
+        ("002596fffe123456", MacNotation::Bare),
+    ];
+    for (s, notation) in cases {
+        let (mac, seen) = MacAddr8::parse_with_notation(s).unwrap();
+        assert_eq!(mac.octets(), expected, "input={}", s);
+        assert_eq!(seen, notation, "input={}", s);
+    }
+    // An EUI-48 string is too short for EUI-64 in every notation.
+    assert_eq!(
+        "0025.9612.3456".parse::<MacAddr8>().unwrap_err().kind(),
+        ParseErrorKind::TooFewComponents
+    );
+    assert_eq!(
+        "002596123456".parse::<MacAddr8>().unwrap_err().kind(),
+        ParseErrorKind::TooFewComponents
+    );
+}
+
+#[test]
+fn notation_separator() {
+    assert_eq!(MacNotation::Colon.separator(), Some(':'));
+    assert_eq!(MacNotation::Dotted.separator(), Some('.'));
+    assert_eq!(MacNotation::Bare.separator(), None);
+}
+
+#[test]
+fn error_positions() {
+    let e = "00:25:96:1g:34:56".parse::<MacAddr>().unwrap_err();
+    assert_eq!(e.kind(), ParseErrorKind::InvalidComponent);
+    assert_eq!(e.offset(), 10);
+    assert_eq!(e.component(), 3);
+    assert_eq!(e.expected_len(), 6);
+
+    let e = "00:25:96:12".parse::<MacAddr8>().unwrap_err();
+    assert_eq!(e.kind(), ParseErrorKind::TooFewComponents);
+    assert_eq!(e.offset(), 11);
+    assert_eq!(e.component(), 4);
+    assert_eq!(e.expected_len(), 8);
+    assert_eq!(
+        e.to_string(),
+        "Too few components in a MAC address string at byte 11 (component 4, expected 8 octets)"
+    );
+
+    let e = "00:25:96-12:34:56".parse::<MacAddr>().unwrap_err();
+    assert_eq!(e.kind(), ParseErrorKind::MixedSeparators);
+    assert_eq!(e.offset(), 8);
+    assert_eq!(e.component(), 2);
+}
+
+#[test]
+fn strict_mode() {
+    let ok = MacAddr::parse_with_mode("00:25:96:12:34:56", ParseMode::Strict).unwrap();
+    assert_eq!(ok.octets(), OCTETS);
+    assert!(MacAddr::parse_with_mode("002596123456", ParseMode::Strict).is_ok());
+
+    let e = MacAddr::parse_with_mode("0:1:2:3:4:5", ParseMode::Strict).unwrap_err();
+    assert_eq!(e.kind(), ParseErrorKind::InvalidComponent);
+    assert_eq!(e.offset(), 1);
+    assert_eq!(e.component(), 0);
+
+    let e = MacAddr::parse_with_mode("00:25:96:12:34:56 ", ParseMode::Strict).unwrap_err();
+    assert_eq!(e.kind(), ParseErrorKind::UnexpectedWhitespace);
+    assert_eq!(e.offset(), 17);
+
+    let e = MacAddr::parse_with_mode("00-25:96:12:34:56", ParseMode::Strict).unwrap_err();
+    assert_eq!(e.kind(), ParseErrorKind::MixedSeparators);
+}
+
+#[test]
+fn lenient_mode() {
+    for s in [
+        "0:25:96:12:34:56",
+        "00:25-96 12:34:56",
+        "  00-25-96-12-34-56\n",
+        "\t0025.9612.3456 ",
+    ] {
+        let mac = MacAddr::parse_with_mode(s, ParseMode::Lenient).unwrap();
+        assert_eq!(mac.octets(), OCTETS, "input={:?}", s);
+    }
+    // Dotted groups cannot be mixed with octet separators, even leniently.
+    let e = MacAddr::parse_with_mode("0025.9612:3456", ParseMode::Lenient).unwrap_err();
+    assert_eq!(e.kind(), ParseErrorKind::MixedSeparators);
+    // Offsets still refer to the untrimmed input.
+    let e = MacAddr::parse_with_mode("  00:25:96:12:34:zz", ParseMode::Lenient).unwrap_err();
+    assert_eq!(e.offset(), 17);
+    assert_eq!(e.component(), 5);
+
+    let e = MacAddr8::parse_with_mode(" 00:25:96 ", ParseMode::Lenient).unwrap_err();
+    assert_eq!(e.kind(), ParseErrorKind::TooFewComponents);
+    assert_eq!(e.offset(), 9);
+}
+
+#[test]
+fn default_mode_is_standard() {
+    assert_eq!(ParseMode::default(), ParseMode::Standard);
+    assert_eq!(
+        " 00:25:96:12:34:56".parse::<MacAddr>().unwrap_err().kind(),
+        ParseErrorKind::UnexpectedWhitespace
+    );
+}
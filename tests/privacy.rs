@@ -0,0 +1,34 @@
+// Privacy classification heuristic tests.
+
+use mac_addr::{Hypervisor, MacAddr, PrivacyClass};
+
+fn class(s: &str) -> PrivacyClass {
+    s.parse::<MacAddr>().unwrap().privacy_class()
+}
+
+#[test]
+fn virtual_and_container_prefixes() {
+    let vm = PrivacyClass::VirtualMachine;
+    assert_eq!(class("00:50:56:c0:00:08"), vm(Hypervisor::VMware));
+    assert_eq!(class("00:0c:29:aa:bb:cc"), vm(Hypervisor::VMware));
+    assert_eq!(class("52:54:00:12:34:56"), vm(Hypervisor::Qemu));
+    assert_eq!(class("00:15:5d:01:02:03"), vm(Hypervisor::HyperV));
+    assert_eq!(class("00:16:3e:7f:00:01"), vm(Hypervisor::Xen));
+    assert_eq!(class("08:00:27:00:00:01"), vm(Hypervisor::VirtualBox));
+    assert_eq!(class("00:1c:42:00:00:01"), vm(Hypervisor::Parallels));
+    assert_eq!(class("02:42:ac:11:00:02"), PrivacyClass::Container);
+    assert_eq!(Hypervisor::Qemu.name(), "QEMU/KVM");
+}
+
+#[test]
+fn universal_and_local() {
+    assert_eq!(class("00:25:96:12:34:56"), PrivacyClass::Universal);
+    assert_eq!(class("01:00:5e:00:00:fb"), PrivacyClass::Universal);
+    // AAI, reserved and SAI quadrants.
+    assert_eq!(class("72:1f:3a:92:04:7c"), PrivacyClass::Randomized);
+    assert_eq!(class("d6:1f:3a:92:04:7c"), PrivacyClass::Randomized);
+    assert_eq!(class("ee:1f:3a:92:04:7c"), PrivacyClass::Randomized);
+    // ELI quadrant and local group addresses.
+    assert_eq!(class("1a:2b:3c:00:00:01"), PrivacyClass::UnknownLocal);
+    assert_eq!(class("33:33:00:00:00:01"), PrivacyClass::UnknownLocal);
+}
@@ -0,0 +1,66 @@
+// Random address generation (only built when the `rand` feature is enabled).
+
+#![cfg(feature = "rand")]
+
+mod common;
+
+use common::{Ones, XorShift};
+use mac_addr::{MacAddr, MacAddr8};
+
+#[test]
+fn local_unicast_bits() {
+    let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..1000 {
+        let m = MacAddr::random_local_unicast(&mut rng);
+        assert!(m.is_locally_administered() && m.is_unicast(), "{}", m);
+        let m8 = MacAddr8::random_local_unicast(&mut rng);
+        assert!(m8.is_locally_administered() && m8.is_unicast(), "{}", m8);
+    }
+    let m = MacAddr::random_local_unicast(&mut Ones);
+    assert_eq!(m, MacAddr::new(0xfe, 0xff, 0xff, 0xff, 0xff, 0xff));
+    let mut rng = XorShift(1);
+    assert_ne!(
+        MacAddr::random_local_unicast(&mut rng),
+        MacAddr::random_local_unicast(&mut rng)
+    );
+}
+
+#[test]
+fn with_oui() {
+    let mut rng = XorShift(42);
+    let m = MacAddr::random_with_oui([0x00, 0x25, 0x96], &mut rng);
+    assert_eq!(m.oui(), [0x00, 0x25, 0x96]);
+    assert!(m.is_universal() && m.is_unicast());
+
+    // A multicast OUI is turned into its unicast counterpart.
+    let m = MacAddr::random_with_oui([0x03, 0x00, 0x00], &mut Ones);
+    assert_eq!(m, MacAddr::new(0x02, 0x00, 0x00, 0xff, 0xff, 0xff));
+
+    let m8 = MacAddr8::random_with_oui([0x00, 0x25, 0x96], &mut rng);
+    assert_eq!(m8.oui(), [0x00, 0x25, 0x96]);
+}
+
+#[test]
+fn in_prefix() {
+    let mut rng = XorShift(7);
+    let base = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x30, 0x00);
+    for _ in 0..100 {
+        let m = MacAddr::random_in_prefix(base, 36, &mut rng).unwrap();
+        assert!(m.matches_masked(base, MacAddr::mask_from_prefix_len(36).unwrap()));
+    }
+    assert_eq!(MacAddr::random_in_prefix(base, 48, &mut Ones), Some(base));
+    assert_eq!(MacAddr::random_in_prefix(base, 49, &mut rng), None);
+
+    // Short prefixes do not cover the flag bits; they are forced.
+    let m = MacAddr::random_in_prefix(MacAddr::zero(), 4, &mut Ones).unwrap();
+    assert_eq!(m, MacAddr::new(0x0e, 0xff, 0xff, 0xff, 0xff, 0xff));
+    let m = MacAddr::random_in_prefix(MacAddr::zero(), 7, &mut Ones).unwrap();
+    assert_eq!(m.octets()[0], 0x00);
+    let m = MacAddr::random_in_prefix(MacAddr::broadcast(), 0, &mut Ones).unwrap();
+    assert!(m.is_locally_administered() && m.is_unicast());
+
+    let base8 = MacAddr8::new(0x00, 0x25, 0x96, 0xff, 0xfe, 0x00, 0x00, 0x00);
+    let m8 = MacAddr8::random_in_prefix(base8, 40, &mut rng).unwrap();
+    assert_eq!(m8.octets()[..5], base8.octets()[..5]);
+    assert_eq!(MacAddr8::random_in_prefix(base8, 65, &mut rng), None);
+}
@@ -0,0 +1,117 @@
+// MacRange iteration, containment and splitting tests.
+
+use mac_addr::{MacAddr, MacAddr8, MacRange};
+
+fn m(last: u8) -> MacAddr {
+    MacAddr::new(0x02, 0x00, 0x00, 0x00, 0x00, last)
+}
+
+#[test]
+fn iterate_both_ends() {
+    let r = MacRange::new(m(0xfe), MacAddr::new(0x02, 0, 0, 0, 1, 0x01));
+    assert_eq!(r.num_addresses(), 4);
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(r.len(), 4);
+    let v: Vec<MacAddr> = r.collect();
+    assert_eq!(
+        v,
+        [
+            m(0xfe),
+            m(0xff),
+            MacAddr::new(0x02, 0, 0, 0, 1, 0x00),
+            MacAddr::new(0x02, 0, 0, 0, 1, 0x01)
+        ]
+    );
+
+    let mut r = MacRange::new(m(1), m(3));
+    assert_eq!(r.next_back(), Some(m(3)));
+    assert_eq!(r.next(), Some(m(1)));
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(r.len(), 1);
+    assert_eq!(r.next_back(), Some(m(2)));
+    assert_eq!(r.next(), None);
+    assert_eq!(r.next_back(), None);
+    assert!(r.is_empty());
+}
+
+#[test]
+fn equality_ignores_iteration_state() {
+    use std::collections::HashSet;
+
+    let fresh = MacRange::new(m(5), m(5));
+    let mut drained = fresh;
+    assert_eq!(drained.next(), Some(m(5)));
+    assert!(drained.is_empty());
+    assert_eq!(drained, fresh);
+    let set: HashSet<_> = [fresh, drained].into_iter().collect();
+    assert_eq!(set.len(), 1);
+    assert_ne!(MacRange::new(m(5), m(6)), fresh);
+}
+
+#[test]
+fn iterate_to_the_top_of_the_space() {
+    let top = MacAddr::broadcast();
+    let r = MacRange::new(top.wrapping_sub(1), top);
+    assert_eq!(r.rev().collect::<Vec<_>>(), [top, top.wrapping_sub(1)]);
+
+    let max8 = MacAddr8::from_u64(u64::MAX);
+    let r8 = MacRange::new(max8, max8);
+    assert_eq!(r8.count(), 1);
+    let full = MacRange::new(MacAddr8::from_u64(0), max8);
+    assert_eq!(full.num_addresses(), 1u128 << 64);
+    assert_eq!(full.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn empty_when_reversed() {
+    let r = MacRange::new(m(5), m(4));
+    assert!(r.is_empty());
+    assert_eq!(r.num_addresses(), 0);
+    assert!(!r.contains(m(4)));
+    assert_eq!(r.count(), 0);
+}
+
+#[test]
+fn contains_overlaps_intersection() {
+    let a = MacRange::new(m(10), m(20));
+    let b = MacRange::new(m(15), m(30));
+    let c = MacRange::new(m(21), m(30));
+    assert!(a.contains(m(10)) && a.contains(m(20)));
+    assert!(!a.contains(m(21)));
+    assert!(a.overlaps(&b));
+    assert!(!a.overlaps(&c));
+    assert_eq!(a.intersection(&b), Some(MacRange::new(m(15), m(20))));
+    assert_eq!(a.intersection(&c), None);
+}
+
+#[test]
+fn split_and_chunks() {
+    let r = MacRange::new(m(0), m(9));
+    let (lo, hi) = r.split_at(m(4)).unwrap();
+    assert_eq!(lo, MacRange::new(m(0), m(3)));
+    assert_eq!(hi, MacRange::new(m(4), m(9)));
+    assert!(r.split_at(m(0)).is_none());
+    assert!(r.split_at(m(10)).is_none());
+    assert!(r.split_at(m(9)).is_some());
+
+    let chunks: Vec<_> = r.chunks(4).collect();
+    assert_eq!(
+        chunks,
+        [
+            MacRange::new(m(0), m(3)),
+            MacRange::new(m(4), m(7)),
+            MacRange::new(m(8), m(9)),
+        ]
+    );
+
+    let max8 = MacAddr8::from_u64(u64::MAX);
+    let tail = MacRange::new(MacAddr8::from_u64(u64::MAX - 2), max8);
+    assert_eq!(tail.chunks(u64::MAX).count(), 1);
+    assert_eq!(tail.chunks(2).count(), 2);
+}
+
+#[test]
+#[should_panic]
+fn zero_sized_chunks_panic() {
+    let _ = MacRange::new(m(0), m(1)).chunks(0);
+}
@@ -35,3 +35,20 @@ fn serde_compact_bincode_roundtrip() {
     let back: Wrapper = bincode::deserialize(&bin).unwrap();
     assert_eq!(back, w);
 }
+
+#[test]
+fn serde_hw_addr_roundtrip() {
+    use mac_addr::HwAddr;
+
+    let a: HwAddr<20> = "80:00:00:48:fe:80:00:00:00:00:00:00:00:02:c9:03:00:a1:b2:c3"
+        .parse()
+        .unwrap();
+    let s = serde_json::to_string(&a).unwrap();
+    assert_eq!(
+        s,
+        "\"80:00:00:48:fe:80:00:00:00:00:00:00:00:02:c9:03:00:a1:b2:c3\""
+    );
+    let back: HwAddr<20> = serde_json::from_str(&s).unwrap();
+    assert_eq!(back, a);
+    assert!(serde_json::from_str::<HwAddr<2>>("\"ab:cd:ef\"").is_err());
+}
@@ -0,0 +1,79 @@
+// IEEE 802c SLAP quadrant tests.
+
+#[cfg(feature = "rand")]
+mod common;
+
+use mac_addr::{MacAddr, MacAddr8, SlapQuadrant};
+
+#[test]
+fn quadrant_classification() {
+    let cases = [
+        (0x02, Some(SlapQuadrant::Aai)),
+        (0x03, Some(SlapQuadrant::Aai)),
+        (0x0a, Some(SlapQuadrant::Eli)),
+        (0x1b, Some(SlapQuadrant::Eli)),
+        (0x0e, Some(SlapQuadrant::Sai)),
+        (0xff, Some(SlapQuadrant::Sai)),
+        (0x06, Some(SlapQuadrant::Reserved)),
+        (0x00, None),
+        (0x01, None),
+        (0xfc, None),
+    ];
+    for (first, expected) in cases {
+        let m = MacAddr::new(first, 0, 0, 0, 0, 1);
+        assert_eq!(m.slap_quadrant(), expected, "first octet {:02x}", first);
+        let m8 = MacAddr8::new(first, 0, 0, 0, 0, 0, 0, 1);
+        assert_eq!(m8.slap_quadrant(), expected);
+    }
+    assert_eq!(SlapQuadrant::Eli.nibble(), 0x0a);
+    assert_eq!(SlapQuadrant::Aai.nibble(), 0x02);
+}
+
+#[test]
+fn eli_from_cid() {
+    let m = MacAddr::eli([0x1a, 0x2b, 0x3c], [0x00, 0x00, 0x01]).unwrap();
+    assert_eq!(m, MacAddr::new(0x1a, 0x2b, 0x3c, 0x00, 0x00, 0x01));
+    assert_eq!(m.slap_quadrant(), Some(SlapQuadrant::Eli));
+    assert!(m.is_unicast());
+
+    // Not a CID: universal OUI, AAI prefix or group CID.
+    assert_eq!(MacAddr::eli([0x00, 0x25, 0x96], [0, 0, 1]), None);
+    assert_eq!(MacAddr::eli([0x02, 0x00, 0x00], [0, 0, 1]), None);
+    assert_eq!(MacAddr::eli([0x0b, 0x00, 0x00], [0, 0, 1]), None);
+
+    let m8 = MacAddr8::eli([0xda, 0x00, 0x01], [1, 2, 3, 4, 5]).unwrap();
+    assert_eq!(m8.slap_quadrant(), Some(SlapQuadrant::Eli));
+}
+
+#[cfg(feature = "rand")]
+mod generation {
+    use super::*;
+    use crate::common::XorShift;
+
+    #[test]
+    fn generators_stay_in_quadrant() {
+        let mut rng = XorShift(3);
+        for _ in 0..500 {
+            let aai = MacAddr::random_aai(&mut rng);
+            assert_eq!(aai.slap_quadrant(), Some(SlapQuadrant::Aai));
+            assert!(aai.is_unicast());
+            let sai = MacAddr::random_sai(&mut rng);
+            assert_eq!(sai.slap_quadrant(), Some(SlapQuadrant::Sai));
+            assert!(sai.is_unicast());
+            let aai8 = MacAddr8::random_aai(&mut rng);
+            assert_eq!(aai8.slap_quadrant(), Some(SlapQuadrant::Aai));
+            let sai8 = MacAddr8::random_sai(&mut rng);
+            assert_eq!(sai8.slap_quadrant(), Some(SlapQuadrant::Sai));
+        }
+    }
+
+    #[test]
+    fn random_eli_keeps_cid() {
+        let mut rng = XorShift(9);
+        let m = MacAddr::random_eli([0x1a, 0x2b, 0x3c], &mut rng).unwrap();
+        assert_eq!(m.oui(), [0x1a, 0x2b, 0x3c]);
+        assert!(MacAddr::random_eli([0x02, 0x00, 0x00], &mut rng).is_none());
+        let m8 = MacAddr8::random_eli([0x1a, 0x2b, 0x3c], &mut rng).unwrap();
+        assert_eq!(m8.oui(), [0x1a, 0x2b, 0x3c]);
+    }
+}
@@ -0,0 +1,60 @@
+// Local interface enumeration tests (Linux, `sys` feature).
+
+#![cfg(all(feature = "sys", target_os = "linux"))]
+
+use mac_addr::MacAddr;
+use mac_addr::sys::{self, Backend, OperState};
+use std::io::ErrorKind;
+
+/// ARPHRD_LOOPBACK from `<linux/if_arp.h>`.
+const ARPHRD_LOOPBACK: u16 = 772;
+
+#[test]
+fn interfaces_include_loopback() {
+    let ifaces = sys::interfaces().unwrap();
+    assert!(ifaces.windows(2).all(|w| w[0].index() < w[1].index()));
+    let lo = ifaces.iter().find(|i| i.name() == "lo").unwrap();
+    assert_eq!(lo.mac_addr(), Some(MacAddr::zero()));
+    assert_eq!(lo.hardware_type(), ARPHRD_LOOPBACK);
+    assert!(lo.index() > 0);
+    assert_ne!(lo.operstate(), OperState::NotPresent);
+}
+
+#[test]
+fn for_interface_backends_agree() {
+    for backend in [Backend::Sysfs, Backend::Ioctl] {
+        assert_eq!(
+            sys::for_interface_with("lo", backend).unwrap(),
+            MacAddr::zero()
+        );
+    }
+    for iface in sys::interfaces().unwrap() {
+        if let Some(mac) = iface.mac_addr() {
+            assert_eq!(sys::for_interface(iface.name()).unwrap(), mac);
+        }
+    }
+}
+
+#[test]
+fn for_interface_errors() {
+    for name in ["", "..", "a/b", "averyveryverylongname"] {
+        assert_eq!(
+            sys::for_interface(name).unwrap_err().kind(),
+            ErrorKind::InvalidInput,
+            "{:?}",
+            name
+        );
+    }
+    assert!(sys::for_interface("nonexistent0").is_err());
+    assert!(sys::for_interface_with("nonexistent0", Backend::Ioctl).is_err());
+}
+
+#[test]
+fn operstate_names() {
+    assert_eq!(OperState::from_name("up"), OperState::Up);
+    assert_eq!(
+        OperState::from_name("lowerlayerdown"),
+        OperState::LowerLayerDown
+    );
+    assert_eq!(OperState::from_name("bogus"), OperState::Unknown);
+}
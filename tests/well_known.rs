@@ -0,0 +1,91 @@
+// Well-known address classification tests.
+
+use mac_addr::{MacAddr, WellKnown, well_known};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+#[test]
+fn catalogue_constants_classify() {
+    let cases = [
+        (MacAddr::broadcast(), WellKnown::Broadcast),
+        (well_known::BRIDGE_GROUP, WellKnown::BridgeGroup),
+        (well_known::PAUSE, WellKnown::Pause),
+        (well_known::SLOW_PROTOCOLS, WellKnown::SlowProtocols),
+        (
+            well_known::NEAREST_NON_TPMR_BRIDGE,
+            WellKnown::NearestNonTpmrBridge,
+        ),
+        (
+            well_known::LLDP_NEAREST_BRIDGE,
+            WellKnown::LldpNearestBridge,
+        ),
+        (well_known::PTP, WellKnown::Ptp),
+        (well_known::CISCO_CDP_VTP, WellKnown::CiscoCdpVtp),
+        (well_known::CISCO_PVST, WellKnown::CiscoPvst),
+        (well_known::DOCUMENTATION, WellKnown::Documentation),
+        (
+            well_known::DOCUMENTATION_MULTICAST,
+            WellKnown::DocumentationMulticast,
+        ),
+    ];
+    for (mac, expected) in cases {
+        assert_eq!(mac.well_known(), Some(expected), "{}", mac);
+        assert!(!expected.description().is_empty());
+    }
+}
+
+#[test]
+fn parameterized_blocks() {
+    assert_eq!(
+        "01:80:c2:00:00:0d".parse::<MacAddr>().unwrap().well_known(),
+        Some(WellKnown::ReservedBridgeGroup(0x0d))
+    );
+    assert_eq!(
+        "01:80:c2:00:00:10".parse::<MacAddr>().unwrap().well_known(),
+        None
+    );
+    assert_eq!(
+        well_known::vrrp_ipv4(200).well_known(),
+        Some(WellKnown::VrrpIpv4(200))
+    );
+    assert_eq!(
+        well_known::vrrp_ipv6(1).well_known(),
+        Some(WellKnown::VrrpIpv6(1))
+    );
+    assert_eq!(
+        well_known::hsrp_v1(10).well_known(),
+        Some(WellKnown::HsrpV1(10))
+    );
+    let v2 = well_known::hsrp_v2(0x123).unwrap();
+    assert_eq!(v2, "00:00:0c:9f:f1:23".parse().unwrap());
+    assert_eq!(v2.well_known(), Some(WellKnown::HsrpV2(0x123)));
+    assert_eq!(well_known::hsrp_v2(0x1000), None);
+    let v6 = well_known::hsrp_ipv6(0xabc).unwrap();
+    assert_eq!(v6, "00:05:73:a0:0a:bc".parse().unwrap());
+    assert_eq!(v6.well_known(), Some(WellKnown::HsrpIpv6(0xabc)));
+    assert_eq!(
+        well_known::hsrp_ipv6(0).unwrap().well_known(),
+        Some(WellKnown::HsrpIpv6(0))
+    );
+    assert_eq!(well_known::hsrp_ipv6(0x1000), None);
+    assert_eq!(
+        MacAddr::new(0x00, 0x05, 0x73, 0xa0, 0x10, 0x00).well_known(),
+        None
+    );
+
+    let v4 = MacAddr::from_ipv4_multicast(Ipv4Addr::new(239, 1, 1, 1)).unwrap();
+    assert_eq!(v4.well_known(), Some(WellKnown::Ipv4Multicast));
+    let v6 = MacAddr::from_ipv6_multicast(Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1)).unwrap();
+    assert_eq!(v6.well_known(), Some(WellKnown::Ipv6Multicast));
+    assert_eq!(MacAddr::new(0x00, 0x25, 0x96, 0, 0, 1).well_known(), None);
+}
+
+#[test]
+fn forwarding_flag() {
+    for n in 0..0x10 {
+        let mac = MacAddr::new(0x01, 0x80, 0xc2, 0x00, 0x00, n);
+        assert!(mac.well_known().unwrap().must_not_forward());
+    }
+    assert!(!WellKnown::Ptp.must_not_forward());
+    assert!(!WellKnown::CiscoCdpVtp.must_not_forward());
+    assert!(!WellKnown::Broadcast.must_not_forward());
+}
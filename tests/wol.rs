@@ -0,0 +1,89 @@
+// Wake-on-LAN magic packet tests.
+
+use mac_addr::wol::{self, MagicPacket};
+use mac_addr::{MacAddr, WolError};
+
+const MAC: MacAddr = MacAddr::new(0x00, 0x25, 0x96, 0x12, 0x34, 0x56);
+
+#[test]
+fn build_layout() {
+    let p = MagicPacket::new(MAC);
+    let bytes = p.as_bytes();
+    assert_eq!(bytes.len(), wol::PACKET_LEN);
+    assert_eq!(&bytes[..6], &[0xff; 6]);
+    for rep in bytes[6..].chunks(6) {
+        assert_eq!(rep, MAC.octets());
+    }
+
+    let p4 = MagicPacket::with_password(MAC, &[1, 2, 3, 4]).unwrap();
+    assert_eq!(p4.as_bytes().len(), 106);
+    assert_eq!(p4.password(), Some(&[1, 2, 3, 4][..]));
+    let p6 = MagicPacket::with_password(MAC, &[1, 2, 3, 4, 5, 6]).unwrap();
+    assert_eq!(&p6.as_bytes()[102..], &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(
+        MagicPacket::with_password(MAC, &[1, 2, 3]),
+        Err(WolError::InvalidPasswordLength)
+    );
+}
+
+#[test]
+fn parse_and_validate() {
+    let p = MagicPacket::with_password(MAC, &[9, 8, 7, 6]).unwrap();
+    assert_eq!(MagicPacket::parse(p.as_bytes()), Ok(p));
+    assert_eq!(MagicPacket::parse(p.as_bytes()).unwrap().mac_addr(), MAC);
+
+    let plain = MagicPacket::new(MAC);
+    let mut bytes = plain.as_bytes().to_vec();
+    assert_eq!(
+        MagicPacket::parse(&bytes[..101]),
+        Err(WolError::InvalidLength)
+    );
+    bytes[3] = 0;
+    assert_eq!(MagicPacket::parse(&bytes), Err(WolError::MissingSync));
+    bytes[3] = 0xff;
+    bytes[100] ^= 1;
+    assert_eq!(
+        MagicPacket::parse(&bytes),
+        Err(WolError::MismatchedRepetition)
+    );
+}
+
+#[test]
+fn find_in_padded_payload() {
+    let packet = MagicPacket::new(MAC);
+    // Leading 0xff bytes must not shift the match off the real sync stream.
+    let mut payload = vec![0x45, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+    payload.extend_from_slice(packet.as_bytes());
+    payload.extend_from_slice(&[0; 20]);
+    assert_eq!(MagicPacket::find(&payload), Some(packet));
+
+    // A password-bearing packet is found without its password.
+    let p4 = MagicPacket::with_password(MAC, &[1, 2, 3, 4]).unwrap();
+    let found = MagicPacket::find(p4.as_bytes()).unwrap();
+    assert_eq!(found.mac_addr(), MAC);
+    assert_eq!(found.password(), None);
+
+    payload[50] ^= 1;
+    assert_eq!(MagicPacket::find(&payload), None);
+    assert_eq!(MagicPacket::find(&payload[..wol::PACKET_LEN - 1]), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn send_over_loopback() {
+    use std::net::UdpSocket;
+    use std::time::Duration;
+
+    let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+    receiver
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    let packet = MagicPacket::with_password(MAC, &[1, 2, 3, 4, 5, 6]).unwrap();
+    packet.send_to(receiver.local_addr().unwrap()).unwrap();
+
+    let mut buf = [0u8; 256];
+    let n = receiver.recv(&mut buf).unwrap();
+    let received = MagicPacket::parse(&buf[..n]).unwrap();
+    assert_eq!(received.mac_addr(), MAC);
+    assert_eq!(received.password(), Some(&[1, 2, 3, 4, 5, 6][..]));
+}